@@ -0,0 +1,49 @@
+// DOT Mog, Susbstrate Gamification Project with C# .NET Standard & Unity3D
+// Copyright (C) 2020-2021 DOT Mog Team, darkfriend77 & metastar77
+//
+// DOT Mog is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as
+// published by the Free Software Foundation, either version 3 of the
+// License.
+// DOT Mog is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+//! Runtime API definition required by the DOTMog RPC extensions.
+//!
+//! This API should be imported and implemented by the runtime,
+//! of a node that wants to use the custom RPC extension
+//! adding the DOTMog queries.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+// The `too_many_arguments` warning originates from `decl_runtime_apis` macro.
+#![allow(clippy::too_many_arguments)]
+// The `unnecessary_mut_passed` warning originates from `decl_runtime_apis` macro.
+#![allow(clippy::unnecessary_mut_passed)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+use pallet_dotmog::{GameEvent, MogwaiFull, game_event::GameEventType, general::BreedingPreview};
+
+sp_api::decl_runtime_apis! {
+	/// The API to query mogwais and game events of the dot mog pallet.
+	pub trait DotMogApi<AccountId, Hash, BlockNumber, Balance> where
+		AccountId: Codec,
+		Hash: Codec,
+		BlockNumber: Codec,
+		Balance: Codec,
+	{
+		/// All mogwais of the account, the owned ones followed by the stashed ones.
+		fn mogwais_of(account: AccountId) -> Vec<Hash>;
+
+		/// A mogwai with its bios, owner, name, auction and pending game events.
+		fn mogwai_full(mogwai_id: Hash) -> Option<MogwaiFull<AccountId, Hash, BlockNumber, Balance>>;
+
+		/// The outcome of breeding two mogwais, `None` if they can't be bred.
+		fn breeding_preview(mogwai_id_1: Hash, mogwai_id_2: Hash) -> Option<BreedingPreview<Balance>>;
+
+		/// The game events executed at the end of the block.
+		fn pending_events_at(block_number: BlockNumber) -> Vec<GameEvent<Hash, BlockNumber, GameEventType>>;
+	}
+}
@@ -0,0 +1,145 @@
+// DOT Mog, Susbstrate Gamification Project with C# .NET Standard & Unity3D
+// Copyright (C) 2020-2021 DOT Mog Team, darkfriend77 & metastar77
+//
+// DOT Mog is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as
+// published by the Free Software Foundation, either version 3 of the
+// License.
+// DOT Mog is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+//! RPC interface for the DOTMog pallet, so clients like the C# SDK can query a mogwai
+//! with a single call, instead of paging through the raw storage.
+
+use std::sync::Arc;
+use codec::Codec;
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+use pallet_dotmog::{GameEvent, MogwaiFull, game_event::GameEventType, general::BreedingPreview};
+
+pub use pallet_dotmog_rpc_runtime_api::DotMogApi as DotMogRuntimeApi;
+
+#[rpc]
+pub trait DotMogApi<BlockHash, AccountId, Hash, BlockNumber, Balance> {
+	/// All mogwais of the account, the owned ones followed by the stashed ones.
+	#[rpc(name = "dotMog_mogwaisOf")]
+	fn mogwais_of(&self, account: AccountId, at: Option<BlockHash>) -> Result<Vec<Hash>>;
+
+	/// A mogwai with its bios, owner, name, auction and pending game events.
+	#[rpc(name = "dotMog_mogwaiFull")]
+	fn mogwai_full(
+		&self,
+		mogwai_id: Hash,
+		at: Option<BlockHash>,
+	) -> Result<Option<MogwaiFull<AccountId, Hash, BlockNumber, Balance>>>;
+
+	/// The outcome of breeding two mogwais, `None` if they can't be bred.
+	#[rpc(name = "dotMog_breedingPreview")]
+	fn breeding_preview(
+		&self,
+		mogwai_id_1: Hash,
+		mogwai_id_2: Hash,
+		at: Option<BlockHash>,
+	) -> Result<Option<BreedingPreview<Balance>>>;
+
+	/// The game events executed at the end of the block.
+	#[rpc(name = "dotMog_pendingEventsAt")]
+	fn pending_events_at(
+		&self,
+		block_number: BlockNumber,
+		at: Option<BlockHash>,
+	) -> Result<Vec<GameEvent<Hash, BlockNumber, GameEventType>>>;
+}
+
+/// A struct that implements the [`DotMogApi`].
+pub struct DotMog<C, B> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> DotMog<C, B> {
+	/// Create new `DotMog` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+}
+
+impl From<Error> for i64 {
+	fn from(e: Error) -> i64 {
+		match e {
+			Error::RuntimeError => 1,
+		}
+	}
+}
+
+fn runtime_error_into_rpc_err(err: impl std::fmt::Debug) -> RpcError {
+	RpcError {
+		code: ErrorCode::ServerError(Error::RuntimeError.into()),
+		message: "Unable to query dot mog.".into(),
+		data: Some(format!("{:?}", err).into()),
+	}
+}
+
+impl<C, Block, AccountId, Hash, BlockNumber, Balance>
+	DotMogApi<<Block as BlockT>::Hash, AccountId, Hash, BlockNumber, Balance> for DotMog<C, Block>
+where
+	Block: BlockT,
+	C: 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: DotMogRuntimeApi<Block, AccountId, Hash, BlockNumber, Balance>,
+	AccountId: Codec,
+	Hash: Codec,
+	BlockNumber: Codec,
+	Balance: Codec,
+{
+	fn mogwais_of(&self, account: AccountId, at: Option<<Block as BlockT>::Hash>) -> Result<Vec<Hash>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.mogwais_of(&at, account).map_err(runtime_error_into_rpc_err)
+	}
+
+	fn mogwai_full(
+		&self,
+		mogwai_id: Hash,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Option<MogwaiFull<AccountId, Hash, BlockNumber, Balance>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.mogwai_full(&at, mogwai_id).map_err(runtime_error_into_rpc_err)
+	}
+
+	fn breeding_preview(
+		&self,
+		mogwai_id_1: Hash,
+		mogwai_id_2: Hash,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Option<BreedingPreview<Balance>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.breeding_preview(&at, mogwai_id_1, mogwai_id_2).map_err(runtime_error_into_rpc_err)
+	}
+
+	fn pending_events_at(
+		&self,
+		block_number: BlockNumber,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Vec<GameEvent<Hash, BlockNumber, GameEventType>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.pending_events_at(&at, block_number).map_err(runtime_error_into_rpc_err)
+	}
+}
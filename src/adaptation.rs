@@ -0,0 +1,63 @@
+// DOT Mog, Susbstrate Gamification Project with C# .NET Standard & Unity3D
+// Copyright (C) 2020-2021 DOT Mog Team, darkfriend77 & metastar77
+//
+// DOT Mog is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as
+// published by the Free Software Foundation, either version 3 of the
+// License.
+// DOT Mog is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+use frame_support::{RuntimeDebug, codec::{Encode, Decode}};
+
+/// The way an adaptation is earned by a mogwai.
+#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug)]
+pub enum AdaptationTrigger {
+	/// unlocked when the mogwai reaches the required level.
+	Level = 0,
+	/// unlocked when the mogwai survives the game event type given as requirement.
+	Event = 1,
+	/// unlocked when a mogwai of at least the required rarity is sacrificed into the mogwai.
+	Sacrifice = 2,
+}
+
+impl Default for AdaptationTrigger { fn default() -> Self { Self::Level } }
+
+/// The effect an adaptation has on the outcomes of the mogwai.
+#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug)]
+pub enum AdaptationEffect {
+	None = 0,
+	/// offspring rarity is raised by one.
+	RarityBoost = 1,
+	/// offspring generation is raised by one.
+	GenerationBoost = 2,
+	/// the dna of the mogwai is always passed on in dominant order.
+	Dominant = 3,
+}
+
+impl Default for AdaptationEffect { fn default() -> Self { Self::None } }
+
+#[derive(Encode, Decode, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct Adaptation<Hash> {
+	pub id: Hash,
+	pub trigger: AdaptationTrigger,
+	pub requirement: u32,
+	pub effect: AdaptationEffect,
+}
+
+impl<Hash> Adaptation<Hash> {
+
+	pub fn is_unlocked_by(&self, trigger: AdaptationTrigger, value: u32) -> bool {
+		if self.trigger != trigger {
+			return false;
+		}
+		match trigger {
+			AdaptationTrigger::Level => value >= self.requirement,
+			AdaptationTrigger::Event => value == self.requirement,
+			AdaptationTrigger::Sacrifice => value >= self.requirement,
+		}
+	}
+}
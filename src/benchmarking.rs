@@ -15,29 +15,509 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-//! Assets pallet benchmarking.
+//! DotMog pallet benchmarking.
 
 use super::*;
 use sp_runtime::traits::Bounded;
 use frame_system::RawOrigin as SystemOrigin;
-use frame_benchmarking::{benchmarks, account, whitelisted_caller};
-use frame_support::traits::Get;
+use frame_benchmarking::{benchmarks, account, whitelisted_caller, impl_benchmark_test_suite};
+use frame_support::traits::{Get, OnFinalize};
 
-use crate::Module as DotMogModule;
+use crate::Pallet as DotMogModule;
+
+const SEED: u32 = 0;
+
+/// Upper bound of bidders used to benchmark the auction finalization, a single auction can take all of them.
+const MAX_BIDDERS: u32 = MAX_BIDDERS_PER_AUCTION as u32;
 
 fn assert_last_event<T: Config>(generic_event: <T as Config>::Event) {
-	let events = frame_system::Module::<T>::events();
+	let events = frame_system::Pallet::<T>::events();
 	let system_event: <T as frame_system::Config>::Event = generic_event.into();
 	// compare to the last event record
 	let frame_system::EventRecord { event, .. } = &events[events.len() - 1];
 	assert_eq!(event, &system_event);
 }
 
-benchmarks!{
-    do_something {
-        let caller: T::AccountId = whitelisted_caller();
-    }: _(SystemOrigin::Signed(caller.clone()), 1u32.into())
-    verify {
-        //assert_last_event::<T>(Event::SomethingStored(1u32.into(), caller).into());
-    }
-}
\ No newline at end of file
+/// an account with enough balance to pay for everything in the game.
+fn funded_account<T: Config>(name: &'static str, index: u32) -> T::AccountId {
+	let who: T::AccountId = account(name, index, SEED);
+	T::Currency::make_free_balance_be(&who, BalanceOf::<T>::max_value() / 4u32.into());
+	who
+}
+
+/// the whitelisted caller, with enough balance.
+fn funded_caller<T: Config>() -> T::AccountId {
+	let caller: T::AccountId = whitelisted_caller();
+	T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 4u32.into());
+	caller
+}
+
+/// a name of the maximum length, distinct for each tag.
+fn max_name<T: Config>(tag: u8) -> Vec<u8> {
+	vec![b'a' + tag % 26; T::MaxNameLength::get() as usize]
+}
+
+/// a price every account can pay, without being reaped.
+fn price<T: Config>() -> BalanceOf<T> {
+	T::Currency::minimum_balance().saturating_mul(10u32.into())
+}
+
+/// leave an expired auction with all its bids and a game event behind, for a mogwai that is gone.
+fn stale_mogwai<T: Config>(index: u32) -> T::Hash {
+	let mogwai_id = T::Hashing::hash_of(&(b"stale", index));
+	// the worst case returns the bids of all bidders
+	let bidders: Vec<T::AccountId> = (0..MAX_BIDDERS_PER_AUCTION as u32)
+		.map(|i| funded_account::<T>("bidder", index * MAX_BIDDERS_PER_AUCTION as u32 + i))
+		.collect();
+	for bidder in bidders.iter() {
+		T::Currency::reserve(bidder, price::<T>()).expect("bidder is funded");
+		Bids::<T>::insert((mogwai_id, bidder.clone()), price::<T>());
+	}
+	MogwaiAuction::<T>::insert(mogwai_id, Auction {
+		mogwai_id,
+		mogwai_owner: bidders[0].clone(),
+		expiry: Zero::zero(),
+		min_bid: price::<T>(),
+		high_bid: price::<T>(),
+		high_bidder: bidders[0].clone(),
+	});
+	BidAccounts::<T>::insert(mogwai_id, bidders);
+	GameEventsOfMogwai::<T>::insert(mogwai_id, vec![mogwai_id]);
+	mogwai_id
+}
+
+/// fill the adaptations up to the maximum, all unlocked by the trigger.
+fn add_adaptations<T: Config>(trigger: AdaptationTrigger) {
+	let mut adaptation_ids = Vec::new();
+	for i in 0..MAX_ADAPTATIONS as u32 {
+		let adaptation_id = T::Hashing::hash_of(&(b"adaptation", i));
+		Adaptations::<T>::insert(adaptation_id, Adaptation {
+			id: adaptation_id,
+			trigger,
+			requirement: 0,
+			effect: AdaptationEffect::RarityBoost,
+		});
+		adaptation_ids.push(adaptation_id);
+	}
+	AdaptationIds::<T>::put(adaptation_ids);
+}
+
+/// mint a mogwai without bios.
+fn mint_mogwai<T: Config>(owner: &T::AccountId, gen: u32, rarity: RarityType) -> T::Hash {
+	let dna = T::Hashing::hash_of(&(owner, DotMogModule::<T>::all_mogwais_count()));
+	DotMogModule::<T>::mint_specific(owner.clone(), dna, gen, rarity, false).expect("owner has space left")
+}
+
+/// mint a hatched mogwai, without any pending game event.
+fn mint_hatched<T: Config>(owner: &T::AccountId, gen: u32, rarity: RarityType) -> T::Hash {
+	let mogwai_id = mint_mogwai::<T>(owner, gen, rarity);
+	let block_number = frame_system::Pallet::<T>::block_number();
+	let mogwai_bios = DotMogModule::<T>::segment(DotMogModule::<T>::mogwai(mogwai_id), T::Hash::default(), block_number);
+	MogwaisBios::<T>::insert(mogwai_id, mogwai_bios);
+	mogwai_id
+}
+
+/// mint a named mogwai, named mogwais move or release a deposit.
+fn mint_named<T: Config>(owner: &T::AccountId, tag: u8) -> Result<T::Hash, &'static str> {
+	let mogwai_id = mint_mogwai::<T>(owner, 0, RarityType::Minor);
+	DotMogModule::<T>::name_mogwai(SystemOrigin::Signed(owner.clone()).into(), mogwai_id, max_name::<T>(tag))?;
+	Ok(mogwai_id)
+}
+
+benchmarks! {
+	update_config {
+		let caller = funded_caller::<T>();
+	}: _(SystemOrigin::Signed(caller.clone()), GameConfigType::MaxMogwaisInAccount, Some(1))
+	verify {
+		assert_eq!(DotMogModule::<T>::account_config(&caller).unwrap_or_default().get(GameConfigType::MaxMogwaisInAccount), 1);
+	}
+
+	set_account_name {
+		let caller = funded_caller::<T>();
+		AccountConfig::<T>::insert(&caller, GameConfig { account_naming: 1, ..Default::default() });
+		let name = max_name::<T>(0);
+	}: _(SystemOrigin::Signed(caller.clone()), name.clone())
+	verify {
+		assert_eq!(DotMogModule::<T>::account_of_name(&name), Some(caller));
+	}
+
+	clear_account_name {
+		let caller = funded_caller::<T>();
+		AccountConfig::<T>::insert(&caller, GameConfig { account_naming: 1, ..Default::default() });
+		DotMogModule::<T>::set_account_name(SystemOrigin::Signed(caller.clone()).into(), max_name::<T>(0))?;
+	}: _(SystemOrigin::Signed(caller.clone()))
+	verify {
+		assert!(DotMogModule::<T>::account_name(&caller).is_none());
+	}
+
+	force_clear_account_name {
+		let who = funded_account::<T>("who", 0);
+		AccountConfig::<T>::insert(&who, GameConfig { account_naming: 1, ..Default::default() });
+		DotMogModule::<T>::set_account_name(SystemOrigin::Signed(who.clone()).into(), max_name::<T>(0))?;
+		let origin = T::ModeratorOrigin::successful_origin();
+	}: _<T::Origin>(origin, who.clone())
+	verify {
+		assert!(DotMogModule::<T>::account_name(&who).is_none());
+	}
+
+	name_mogwai {
+		let caller = funded_caller::<T>();
+		let mogwai_id = mint_mogwai::<T>(&caller, 0, RarityType::Minor);
+		let name = max_name::<T>(0);
+	}: _(SystemOrigin::Signed(caller.clone()), mogwai_id, name.clone())
+	verify {
+		assert_eq!(DotMogModule::<T>::mogwai_of_name(&name), Some(mogwai_id));
+	}
+
+	set_price {
+		let caller = funded_caller::<T>();
+		let mogwai_id = mint_mogwai::<T>(&caller, 0, RarityType::Minor);
+	}: _(SystemOrigin::Signed(caller.clone()), mogwai_id, price::<T>())
+	verify {
+		assert_eq!(DotMogModule::<T>::mogwai(mogwai_id).price, price::<T>());
+		assert_eq!(DotMogModule::<T>::lock_of(mogwai_id), Some(MogwaiLock::Listed));
+	}
+
+	create_mogwai {
+		let caller = funded_caller::<T>();
+	}: _(SystemOrigin::Signed(caller.clone()))
+	verify {
+		assert_eq!(DotMogModule::<T>::owned_mogwais_count(&caller), 1);
+	}
+
+	mint_to {
+		add_adaptations::<T>(AdaptationTrigger::Level);
+		let to = funded_account::<T>("to", 0);
+		let dna = T::Hashing::hash_of(&to);
+		let origin = T::AdminOrigin::successful_origin();
+	}: _<T::Origin>(origin, to.clone(), dna, MAX_GENERATION, RarityType::Legendary, true)
+	verify {
+		let mogwai_id = DotMogModule::<T>::mogwai_of_owner_by_index((to, 0));
+		assert_eq!(DotMogModule::<T>::mogwai_bios(mogwai_id).adaptations.len(), MAX_ADAPTATIONS);
+	}
+
+	mint_to_batch {
+		let n in 1 .. MAX_MINT_BATCH as u32;
+		add_adaptations::<T>(AdaptationTrigger::Level);
+		let mogwais: Vec<_> = (0..n).map(|i| {
+			let to: T::AccountId = account("to", i, SEED);
+			let dna = T::Hashing::hash_of(&to);
+			(to, dna, MAX_GENERATION, RarityType::Legendary)
+		}).collect();
+		let all_mogwais_count = DotMogModule::<T>::all_mogwais_count();
+		let origin = T::AdminOrigin::successful_origin();
+	}: _<T::Origin>(origin, mogwais, true)
+	verify {
+		assert_eq!(DotMogModule::<T>::all_mogwais_count(), all_mogwais_count + n as u64);
+	}
+
+	remove_mogwai {
+		frame_system::Pallet::<T>::set_block_number(1u32.into());
+		let caller = funded_caller::<T>();
+		Key::<T>::put(&caller);
+		let mogwai_id = mint_named::<T>(&caller, 0)?;
+		// the forced removal cancels the auction and returns the bid
+		DotMogModule::<T>::create_auction(SystemOrigin::Signed(caller.clone()).into(), mogwai_id, price::<T>(), 10u32.into())?;
+		// the worst case returns the bids of all bidders
+		let bidders: Vec<T::AccountId> = (0..MAX_BIDDERS_PER_AUCTION as u32).map(|i| funded_account::<T>("bidder", i)).collect();
+		for (i, bidder) in bidders.iter().enumerate() {
+			let bid = price::<T>() + (i as u32 + 1).into();
+			DotMogModule::<T>::bid_auction(SystemOrigin::Signed(bidder.clone()).into(), mogwai_id, bid)?;
+		}
+	}: _(SystemOrigin::Signed(caller.clone()), mogwai_id)
+	verify {
+		assert!(DotMogModule::<T>::owner_of(mogwai_id).is_none());
+		assert!(DotMogModule::<T>::auction_of(mogwai_id).is_none());
+		for bidder in bidders.iter() {
+			assert!(T::Currency::reserved_balance(bidder).is_zero());
+		}
+	}
+
+	transfer {
+		let caller = funded_caller::<T>();
+		Key::<T>::put(&caller);
+		let to = funded_account::<T>("to", 0);
+		let mogwai_id = mint_named::<T>(&caller, 0)?;
+	}: _(SystemOrigin::Signed(caller.clone()), to.clone(), mogwai_id)
+	verify {
+		assert_eq!(DotMogModule::<T>::owner_of(mogwai_id), Some(to));
+	}
+
+	stash_mogwai {
+		let caller = funded_caller::<T>();
+		let mogwai_id = mint_mogwai::<T>(&caller, 0, RarityType::Minor);
+	}: _(SystemOrigin::Signed(caller.clone()), mogwai_id)
+	verify {
+		assert!(DotMogModule::<T>::is_stashed(mogwai_id));
+	}
+
+	unstash_mogwai {
+		let caller = funded_caller::<T>();
+		let mogwai_id = mint_mogwai::<T>(&caller, 0, RarityType::Minor);
+		DotMogModule::<T>::stash_mogwai(SystemOrigin::Signed(caller.clone()).into(), mogwai_id)?;
+	}: _(SystemOrigin::Signed(caller.clone()), mogwai_id)
+	verify {
+		assert!(!DotMogModule::<T>::is_stashed(mogwai_id));
+	}
+
+	sacrifice {
+		let caller = funded_caller::<T>();
+		let mogwai_id = mint_hatched::<T>(&caller, 1, RarityType::Normal);
+	}: _(SystemOrigin::Signed(caller.clone()), mogwai_id)
+	verify {
+		assert!(DotMogModule::<T>::owner_of(mogwai_id).is_none());
+	}
+
+	sacrifice_into {
+		add_adaptations::<T>(AdaptationTrigger::Sacrifice);
+		let caller = funded_caller::<T>();
+		let mogwai_id_1 = mint_hatched::<T>(&caller, MAX_GENERATION, RarityType::Legendary);
+		let mogwai_id_2 = mint_hatched::<T>(&caller, 1, RarityType::Normal);
+	}: _(SystemOrigin::Signed(caller.clone()), mogwai_id_1, mogwai_id_2)
+	verify {
+		assert!(DotMogModule::<T>::owner_of(mogwai_id_1).is_none());
+		assert_eq!(DotMogModule::<T>::mogwai_bios(mogwai_id_2).adaptations.len(), MAX_ADAPTATIONS);
+	}
+
+	set_key {
+		let new_key: T::AccountId = account("key", 0, SEED);
+	}: _(SystemOrigin::Root, new_key.clone())
+	verify {
+		assert_last_event::<T>(Event::<T>::KeyChanged(new_key).into());
+	}
+
+	set_treasury {
+		let treasury: T::AccountId = account("treasury", 0, SEED);
+		let origin = T::AdminOrigin::successful_origin();
+	}: _<T::Origin>(origin, Some(treasury.clone()))
+	verify {
+		assert_eq!(DotMogModule::<T>::treasury(), Some(treasury));
+	}
+
+	set_game_activated {
+		let origin = T::AdminOrigin::successful_origin();
+	}: _<T::Origin>(origin, false)
+	verify {
+		assert!(!DotMogModule::<T>::game_activated());
+	}
+
+	set_pricing {
+		let table = vec![price::<T>(); Pricing::MAX_TABLE_LENGTH];
+		let origin = T::AdminOrigin::successful_origin();
+	}: _<T::Origin>(origin, PriceType::Pairing, table.clone())
+	verify {
+		assert_eq!(DotMogModule::<T>::price_table(PriceType::Pairing), table);
+	}
+
+	set_feature_status {
+		let origin = T::AdminOrigin::successful_origin();
+	}: _<T::Origin>(origin, GameFeature::Breeding, false)
+	verify {
+		assert!(!DotMogModule::<T>::is_feature_enabled(GameFeature::Breeding));
+	}
+
+	set_randomness_mode {
+		let origin = T::AdminOrigin::successful_origin();
+	}: _<T::Origin>(origin, RandomnessMode::FutureBlock)
+	verify {
+		assert_eq!(DotMogModule::<T>::randomness_mode(), RandomnessMode::FutureBlock);
+	}
+
+	set_legacy_hatching {
+		let origin = T::AdminOrigin::successful_origin();
+	}: _<T::Origin>(origin, true)
+	verify {
+		assert!(DotMogModule::<T>::legacy_hatching());
+	}
+
+	add_adaptation {
+		add_adaptations::<T>(AdaptationTrigger::Level);
+		AdaptationIds::<T>::mutate(|adaptation_ids| adaptation_ids.pop());
+		let origin = T::AdminOrigin::successful_origin();
+	}: _<T::Origin>(origin, AdaptationTrigger::Level, 1, AdaptationEffect::Dominant)
+	verify {
+		assert_eq!(DotMogModule::<T>::adaptation_ids().len(), MAX_ADAPTATIONS);
+	}
+
+	feed_mogwai {
+		let caller = funded_caller::<T>();
+		let mogwai_id = mint_hatched::<T>(&caller, 1, RarityType::Normal);
+		let amount = T::MinFeedAmount::get();
+	}: _(SystemOrigin::Signed(caller.clone()), mogwai_id, amount)
+	verify {
+		assert_eq!(DotMogModule::<T>::mogwai_bios(mogwai_id).intrinsic, amount);
+	}
+
+	buy_mogwai {
+		let seller = funded_account::<T>("seller", 0);
+		let mogwai_id = mint_named::<T>(&seller, 0)?;
+		DotMogModule::<T>::set_price(SystemOrigin::Signed(seller.clone()).into(), mogwai_id, price::<T>())?;
+		let caller = funded_caller::<T>();
+	}: _(SystemOrigin::Signed(caller.clone()), mogwai_id, price::<T>())
+	verify {
+		assert_eq!(DotMogModule::<T>::owner_of(mogwai_id), Some(caller));
+	}
+
+	morph_mogwai {
+		let caller = funded_caller::<T>();
+		let mogwai_id = mint_mogwai::<T>(&caller, 0, RarityType::Minor);
+	}: _(SystemOrigin::Signed(caller.clone()), mogwai_id)
+
+	breed_mogwai {
+		add_adaptations::<T>(AdaptationTrigger::Level);
+		let caller = funded_caller::<T>();
+		let mogwai_id_1 = mint_hatched::<T>(&caller, 1, RarityType::Legendary);
+		let mogwai_id_2 = mint_hatched::<T>(&caller, 1, RarityType::Legendary);
+		// all adaptations take effect on the offspring
+		for mogwai_id in [mogwai_id_1, mogwai_id_2].iter() {
+			MogwaisBios::<T>::mutate(mogwai_id, |mogwai_bios| mogwai_bios.adaptations = DotMogModule::<T>::adaptation_ids());
+		}
+		// the outcome range is computed and the parents are kept until hatching
+		BreedingRandomness::<T>::put(RandomnessMode::FutureBlock);
+	}: _(SystemOrigin::Signed(caller.clone()), mogwai_id_1, mogwai_id_2)
+	verify {
+		assert_eq!(DotMogModule::<T>::owned_mogwais_count(&caller), 3);
+		let offspring_id = DotMogModule::<T>::mogwai_of_owner_by_index((caller, 2));
+		assert!(DotMogModule::<T>::pending_breed(offspring_id).is_some());
+	}
+
+	create_auction {
+		frame_system::Pallet::<T>::set_block_number(1u32.into());
+		let expiry: T::BlockNumber = 10u32.into();
+		// the target block already holds all other auctions
+		let seller = funded_account::<T>("seller", 0);
+		for _ in 1..MAX_AUCTIONS_PER_BLOCK {
+			let mogwai_id = mint_mogwai::<T>(&seller, 0, RarityType::Minor);
+			DotMogModule::<T>::create_auction(SystemOrigin::Signed(seller.clone()).into(), mogwai_id, price::<T>(), expiry)?;
+		}
+		let caller = funded_caller::<T>();
+		let mogwai_id = mint_mogwai::<T>(&caller, 0, RarityType::Minor);
+	}: _(SystemOrigin::Signed(caller.clone()), mogwai_id, price::<T>(), expiry)
+	verify {
+		assert_eq!(DotMogModule::<T>::auctions_expire_at(expiry).len(), MAX_AUCTIONS_PER_BLOCK);
+	}
+
+	bid_auction {
+		frame_system::Pallet::<T>::set_block_number(1u32.into());
+		let expiry: T::BlockNumber = 10u32.into();
+		let seller = funded_account::<T>("seller", 0);
+		let mogwai_id = mint_mogwai::<T>(&seller, 0, RarityType::Minor);
+		DotMogModule::<T>::create_auction(SystemOrigin::Signed(seller.clone()).into(), mogwai_id, price::<T>(), expiry)?;
+		// raising an own bid only reserves the difference
+		let caller = funded_caller::<T>();
+		DotMogModule::<T>::bid_auction(SystemOrigin::Signed(caller.clone()).into(), mogwai_id, price::<T>() + 1u32.into())?;
+		let bid = price::<T>() + 2u32.into();
+	}: _(SystemOrigin::Signed(caller.clone()), mogwai_id, bid)
+	verify {
+		assert_eq!(DotMogModule::<T>::bid_of((mogwai_id, caller)), bid);
+	}
+
+	reap_stale {
+		let k in 1 .. MAX_REAP_KEYS as u32;
+		frame_system::Pallet::<T>::set_block_number(1u32.into());
+		let mogwai_ids: Vec<T::Hash> = (0..k).map(|i| stale_mogwai::<T>(i)).collect();
+		let caller = funded_caller::<T>();
+		// the rewards are paid from the pallet account
+		T::Currency::make_free_balance_be(&DotMogModule::<T>::reward_account(), BalanceOf::<T>::max_value() / 4u32.into());
+	}: _(SystemOrigin::Signed(caller.clone()), mogwai_ids.clone())
+	verify {
+		for mogwai_id in mogwai_ids.iter() {
+			assert!(DotMogModule::<T>::auction_of(mogwai_id).is_none());
+			assert!(DotMogModule::<T>::bid_accounts(mogwai_id).is_empty());
+		}
+	}
+
+	on_finalize_auctions {
+		let a in 1 .. MAX_AUCTIONS_PER_BLOCK as u32;
+		let b in 1 .. MAX_BIDDERS;
+		frame_system::Pallet::<T>::set_block_number(1u32.into());
+		let expiry: T::BlockNumber = 10u32.into();
+		let seller = funded_account::<T>("seller", 0);
+		let mogwai_ids: Vec<T::Hash> = (0..a).map(|_| mint_mogwai::<T>(&seller, 0, RarityType::Minor)).collect();
+		for mogwai_id in mogwai_ids.iter() {
+			DotMogModule::<T>::create_auction(SystemOrigin::Signed(seller.clone()).into(), *mogwai_id, price::<T>(), expiry)?;
+		}
+		// the bidders are spread over the auctions, each outbidding the former one
+		for i in 0..b {
+			let bidder = funded_account::<T>("bidder", i);
+			let bid = price::<T>() + (i + 1).into();
+			DotMogModule::<T>::bid_auction(SystemOrigin::Signed(bidder).into(), mogwai_ids[(i % a) as usize], bid)?;
+		}
+		frame_system::Pallet::<T>::set_block_number(expiry);
+	}: {
+		DotMogModule::<T>::on_finalize(expiry);
+	}
+	verify {
+		for mogwai_id in mogwai_ids.iter() {
+			assert!(DotMogModule::<T>::auction_of(mogwai_id).is_none());
+		}
+	}
+
+	on_finalize_events {
+		let e in 1 .. MAX_EVENTS_PER_BLOCK as u32;
+		add_adaptations::<T>(AdaptationTrigger::Level);
+		frame_system::Pallet::<T>::set_block_number(1u32.into());
+		let begin: T::BlockNumber = 10u32.into();
+		let mut mogwai_ids = Vec::new();
+		for i in 0..e {
+			let owner: T::AccountId = account("owner", i, SEED);
+			let mogwai_id = DotMogModule::<T>::generate_random_hash(b"benchmark", owner.clone());
+			let mogwai_struct = MogwaiStruct {
+				id: mogwai_id,
+				dna: mogwai_id,
+				genesis: frame_system::Pallet::<T>::block_number(),
+				price: Zero::zero(),
+				gen: 1,
+				rarity: RarityType::Normal,
+			};
+			let game_event = GameEvent {
+				id: DotMogModule::<T>::generate_random_hash(b"benchmark_event", owner.clone()),
+				begin,
+				duration: 0,
+				event_type: GameEventType::Hatch,
+				hashes: vec![mogwai_id],
+				value: 0,
+			};
+			DotMogModule::<T>::mint(owner, mogwai_id, mogwai_struct, Some(game_event))?;
+			MogwaiLocks::<T>::insert(mogwai_id, MogwaiLock::Incubating);
+			// the breeding outcome is drawn at hatching
+			PendingBreeds::<T>::insert(mogwai_id, PendingBreed {
+				parents: [mogwai_id, mogwai_id],
+				gens: [1, 1],
+				rarities: [RarityType::Normal, RarityType::Normal],
+				strands: [[1u8; 16], [2u8; 16]],
+				effects: [vec![AdaptationEffect::RarityBoost; MAX_ADAPTATIONS], vec![AdaptationEffect::RarityBoost; MAX_ADAPTATIONS]],
+			});
+			mogwai_ids.push(mogwai_id);
+		}
+		frame_system::Pallet::<T>::set_block_number(begin);
+	}: {
+		DotMogModule::<T>::on_finalize(begin);
+	}
+	verify {
+		for mogwai_id in mogwai_ids.iter() {
+			assert_eq!(DotMogModule::<T>::mogwai_bios(mogwai_id).adaptations.len(), MAX_ADAPTATIONS);
+		}
+	}
+
+	on_finalize_sweep {
+		let s in 1 .. MAX_SWEEP_PER_BLOCK;
+		frame_system::Pallet::<T>::set_block_number(1u32.into());
+		// each swept key leaves an auction, a bid and a game event behind
+		let mogwai_ids: Vec<T::Hash> = (0..s).map(|i| stale_mogwai::<T>(i)).collect();
+	}: {
+		DotMogModule::<T>::sweep_stale(s);
+	}
+	verify {
+		for mogwai_id in mogwai_ids.iter() {
+			assert!(DotMogModule::<T>::auction_of(mogwai_id).is_none());
+			assert!(DotMogModule::<T>::game_events_of_mogwai(mogwai_id).is_empty());
+		}
+	}
+}
+
+impl_benchmark_test_suite!(
+	DotMogModule,
+	crate::mock::new_test_ext(),
+	crate::mock::Test,
+);
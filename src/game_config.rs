@@ -10,10 +10,11 @@
 // MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
 // GNU Affero General Public License for more details.
 
-use frame_support::{codec::{Encode, Decode}};
-use sp_std::vec::{Vec};
+use frame_support::{RuntimeDebug, codec::{Encode, Decode}};
+#[cfg(feature = "std")]
+use serde::{Serialize, Deserialize};
 
-#[derive(Encode, Decode, Clone, PartialEq)]
+#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug)]
 pub enum GameConfigType {
 	Activated = 0,
 	MaxMogwaisInAccount = 1,
@@ -23,30 +24,94 @@ pub enum GameConfigType {
 
 impl Default for GameConfigType { fn default() -> Self { Self::Activated } }
 
+/// The game features, that can be disabled individually, as bitflags.
+#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug)]
+pub enum GameFeature {
+	Breeding = 1,
+	Sacrifice = 2,
+	Auctions = 4,
+	DirectSales = 8,
+	ConfigUpgrades = 16,
+}
+
+impl GameFeature {
+	pub fn flag(self) -> u8 {
+		self as u8
+	}
+}
+
+/// The way the randomness of a breeding is drawn.
+#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum RandomnessMode {
+	/// the outcome is drawn in the block of the breeding.
+	Instant = 0,
+	/// the outcome is drawn when the mogwai hatches, from randomness unknown at the breeding.
+	FutureBlock = 1,
+}
+
+impl Default for RandomnessMode { fn default() -> Self { Self::Instant } }
+
+/// The upgrade levels of the configuration of an account.
 #[derive(Encode, Decode, Default, Clone, PartialEq)]
-#[cfg_attr(feature = "std", derive(Debug))]
-pub struct GameConfig{
-	pub parameters: Vec<u8>
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct GameConfig {
+	pub max_mogwais_in_account: u8,
+	pub max_stash_size: u8,
+	pub account_naming: u8,
 }
 
 impl GameConfig {
 
-	pub const PARAM_COUNT: u8 = 8;
-
 	pub fn new() -> Self {
-		let mut v = Vec::new();
-		for _i in 0..GameConfig::PARAM_COUNT {
-			v.push(0);
+		Default::default()
+	}
+
+	/// migrate the old untyped parameters, indexed by the game config type, invalid values fall back to level 0.
+	pub fn from_parameters(parameters: &[u8]) -> Self {
+		let mut game_config = GameConfig::new();
+		for config_type in [GameConfigType::MaxMogwaisInAccount, GameConfigType::MaxStashSize, GameConfigType::AccountNaming].iter() {
+			let value = parameters.get(*config_type as usize).copied().unwrap_or(0);
+			if value <= GameConfig::max_value(*config_type) {
+				game_config.set(*config_type, value);
+			}
 		}
-		return GameConfig {
-			parameters: v,
-		};
+		game_config
 	}
-	pub fn config_value(index: u8, value: u8) -> u32 {
+
+	pub fn get(&self, config_type: GameConfigType) -> u8 {
+		match config_type {
+			GameConfigType::MaxMogwaisInAccount => self.max_mogwais_in_account,
+			GameConfigType::MaxStashSize => self.max_stash_size,
+			GameConfigType::AccountNaming => self.account_naming,
+			GameConfigType::Activated => 0,
+		}
+	}
+
+	pub fn set(&mut self, config_type: GameConfigType, value: u8) {
+		match config_type {
+			GameConfigType::MaxMogwaisInAccount => self.max_mogwais_in_account = value,
+			GameConfigType::MaxStashSize => self.max_stash_size = value,
+			GameConfigType::AccountNaming => self.account_naming = value,
+			GameConfigType::Activated => { },
+		}
+	}
+
+	/// the highest level a configuration can be upgraded to.
+	pub fn max_value(config_type: GameConfigType) -> u8 {
+		match config_type {
+			GameConfigType::MaxMogwaisInAccount => 3,
+			GameConfigType::MaxStashSize => 3,
+			GameConfigType::AccountNaming => 1,
+			_ => 0,
+		}
+	}
+
+	pub fn config_value(config_type: GameConfigType, value: u8) -> u32 {
 		let result:u32;
-		match index {
-			// MaxMogwaisInAccount
-            1 => {
+		match config_type {
+			GameConfigType::MaxMogwaisInAccount => {
 				match value {
 					0 => result = 6,
 					1 => result = 12,
@@ -55,28 +120,32 @@ impl GameConfig {
 					_ => result = 0,
 				}
 			},
-            _ => result = 0,
-		}
-		result
-	}
-	pub fn verify_update(index: u8, value: u8, update_value_opt: Option<u8>) -> u8 {
-		let mut result:u8;
-		match index {
-			// MaxMogwaisInAccount
-            1 => {
+			GameConfigType::MaxStashSize => {
 				match value {
-					0 => result = 1,
-					1 => result = 2,
-					2 => result = 3,
+					0 => result = 3,
+					1 => result = 6,
+					2 => result = 9,
+					3 => result = 12,
 					_ => result = 0,
 				}
 			},
-            _ => result = 0,
+			GameConfigType::AccountNaming => result = value as u32,
+			_ => result = 0,
 		}
+		result
+	}
+
+	/// the next level of the configuration, only upgrades by one level are allowed.
+	pub fn verify_update(&self, config_type: GameConfigType, update_value_opt: Option<u8>) -> Option<u8> {
+		let current_value = self.get(config_type);
+		if current_value >= GameConfig::max_value(config_type) {
+			return None;
+		}
+		let result = current_value + 1;
 		// don't allow bad requests
 		if update_value_opt.is_some() && result != update_value_opt.unwrap() {
-			result = 0;
+			return None;
 		}
-		result
+		Some(result)
 	}
 }
\ No newline at end of file
@@ -10,13 +10,18 @@
 // MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
 // GNU Affero General Public License for more details.
 
-use frame_support::{codec::{Encode, Decode}};
+use frame_support::{RuntimeDebug, codec::{Encode, Decode}};
 //use sp_runtime::{traits::{Hash}};
+#[cfg(feature = "std")]
+use serde::{Serialize, Deserialize};
 
-#[derive(Encode, Decode, Clone, PartialEq)]
+#[derive(Encode, Decode, Clone, PartialEq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum GameEventType {
 	Default = 0,
 	Hatch = 1,
+	Grow = 2,
+	Mature = 3,
 }
 
 impl Default for GameEventType { fn default() -> Self { Self::Default } }
@@ -26,6 +31,8 @@ impl GameEventType {
 	pub fn time_till(game_type: GameEventType) -> u16 {
 		match game_type {
 			GameEventType::Hatch => 100,
+			GameEventType::Grow => 500,
+			GameEventType::Mature => 1000,
 			GameEventType::Default => 0,
 		}
 	}
@@ -33,7 +40,27 @@ impl GameEventType {
 	pub fn duration(game_type: GameEventType) -> u16 {
 		match game_type {
 			GameEventType::Hatch => 0,
+			GameEventType::Grow => 0,
+			GameEventType::Mature => 0,
 			GameEventType::Default => 0,
 		}
 	}
+
+	/// the growth phase following the given one, if there is any.
+	pub fn next_phase(game_type: GameEventType) -> Option<GameEventType> {
+		match game_type {
+			GameEventType::Hatch => Some(GameEventType::Grow),
+			GameEventType::Grow => Some(GameEventType::Mature),
+			GameEventType::Mature => None,
+			GameEventType::Default => None,
+		}
+	}
+
+	/// growth phases follow the hatch on their own, they don't hold a mogwai back.
+	pub fn is_growth(game_type: GameEventType) -> bool {
+		match game_type {
+			GameEventType::Grow | GameEventType::Mature => true,
+			GameEventType::Hatch | GameEventType::Default => false,
+		}
+	}
 }
\ No newline at end of file
@@ -10,12 +10,17 @@
 // MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
 // GNU Affero General Public License for more details.
 
-use frame_support::{codec::{Encode, Decode}};
-use sp_std::vec::{Vec};
+use frame_support::{RuntimeDebug, codec::{Encode, Decode}};
+use sp_runtime::traits::{Zero, AtLeast32BitUnsigned};
+use sp_std::{vec, vec::{Vec}};
+#[cfg(feature = "std")]
+use serde::{Serialize, Deserialize};
 
-type Balance = u128;
+use crate::adaptation::AdaptationEffect;
+use crate::game_config::GameConfigType;
 
-#[derive(Encode, Decode, Clone, PartialEq)]
+#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum BreedType {
 	DomDom = 0,
 	DomRez = 1,
@@ -23,7 +28,30 @@ pub enum BreedType {
 	RezRez = 3,
 }
 
-#[derive(Encode, Decode, Copy, Clone, PartialEq)]
+impl BreedType {
+    /// the breed type of a block, each one lasts 20 blocks in a cycle of 80 blocks.
+    pub fn of_block(block_number: u64) -> BreedType {
+        match block_number % 80 {
+            0..=19 => BreedType::DomDom,
+            20..=39 => BreedType::DomRez,
+            40..=59 => BreedType::RezDom,
+            _ => BreedType::RezRez,
+        }
+    }
+
+    /// the breed type drawn from the first byte of the entropy.
+    pub fn of_entropy(entropy: &[u8]) -> BreedType {
+        match entropy.first().copied().unwrap_or(0) % 4 {
+            0 => BreedType::DomDom,
+            1 => BreedType::DomRez,
+            2 => BreedType::RezDom,
+            _ => BreedType::RezRez,
+        }
+    }
+}
+
+#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum RarityType {
 	Minor = 0,
 	Normal = 1,
@@ -47,8 +75,15 @@ impl RarityType {
     }
 }
 
-pub const MILLIMOGS: Balance = 1_000_000_000_000;
-pub const DMOGS: Balance = 1_000 * MILLIMOGS;
+/// an amount of milli mogs, 10^12 of the smallest unit, in any balance type.
+pub fn millimogs<B: AtLeast32BitUnsigned>(amount: u32) -> B {
+    B::from(amount).saturating_mul(B::from(1_000_000u32)).saturating_mul(B::from(1_000_000u32))
+}
+
+/// an amount of dmogs, a thousand milli mogs, in any balance type.
+pub fn dmogs<B: AtLeast32BitUnsigned>(amount: u32) -> B {
+    millimogs::<B>(amount).saturating_mul(B::from(1_000u32))
+}
 
 #[derive(Encode, Decode, Copy, Clone, PartialEq)]
 pub enum FeeType {
@@ -58,79 +93,121 @@ pub enum FeeType {
 
 impl Default for FeeType { fn default() -> Self { Self::Default }}
 
+/// The price tables, that can be changed by governance.
+#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum PriceType {
+	/// fees indexed by the fee type.
+	Fee = 0,
+	/// pairing prices indexed by the sum of both rarities.
+	Pairing = 1,
+	/// intrinsic value divisor on sacrifice indexed by the number of phases.
+	IntrinsicReturn = 2,
+	/// upgrade prices of the max. mogwais in account, indexed by the level.
+	ConfigMaxMogwais = 3,
+	/// upgrade prices of the max. stash size, indexed by the level.
+	ConfigMaxStash = 4,
+	/// unlock prices of the account naming, indexed by the level.
+	ConfigAccountNaming = 5,
+}
+
+impl PriceType {
+    pub const ALL: [PriceType; 6] = [
+        PriceType::Fee, PriceType::Pairing, PriceType::IntrinsicReturn,
+        PriceType::ConfigMaxMogwais, PriceType::ConfigMaxStash, PriceType::ConfigAccountNaming,
+    ];
+
+    /// the price table used for the upgrade of a configuration.
+    pub fn of_config(config_type: GameConfigType) -> Option<PriceType> {
+        match config_type {
+            GameConfigType::MaxMogwaisInAccount => Some(PriceType::ConfigMaxMogwais),
+            GameConfigType::MaxStashSize => Some(PriceType::ConfigMaxStash),
+            GameConfigType::AccountNaming => Some(PriceType::ConfigAccountNaming),
+            GameConfigType::Activated => None,
+        }
+    }
+}
+
 pub struct Pricing;
 impl Pricing {
-    pub fn config_update_price(index: u8, value: u8) -> Balance {
-        let price:Balance;
-        match index {
-			// Config max. Mogwais in account
-            1 => price = Self::config_max_mogwais(value),
-            _ => price = 0,
-		}
-        price
-    }
-    fn config_max_mogwais(value: u8) -> Balance {
-        let price:Balance;
-        match value {
-            1 => price =    5 * DMOGS,
-            2 => price =   10 * DMOGS,
-            3 => price =   20 * DMOGS,
-            _ => price =    0 * DMOGS,
+    /// the default price tables, indices beyond the end use the last entry.
+    pub fn default_table<B: AtLeast32BitUnsigned>(price_type: PriceType) -> Vec<B> {
+        match price_type {
+            PriceType::Fee => vec![
+                millimogs(1),       // FeeType::Default
+                millimogs(50),      // FeeType::Remove
+            ],
+            PriceType::Pairing => [10, 100, 200, 300, 400, 500, 1000, 1500, 2000, 10000].iter()
+                .map(|price| millimogs(*price)).collect(),
+            PriceType::IntrinsicReturn => [10u32, 4, 2, 1, 10].iter().map(|divisor| B::from(*divisor)).collect(),
+            PriceType::ConfigMaxMogwais => vec![Zero::zero(), dmogs(5), dmogs(10), dmogs(20), Zero::zero()],
+            PriceType::ConfigMaxStash => vec![Zero::zero(), dmogs(2), dmogs(5), dmogs(10), Zero::zero()],
+            PriceType::ConfigAccountNaming => vec![Zero::zero(), dmogs(1), Zero::zero()],
         }
-        price
     }
-    pub fn fee_price(fee: FeeType) -> Balance {
-        let price:Balance;
-        match fee {
-            FeeType::Default => price =    1 * MILLIMOGS,
-            FeeType::Remove  => price =   50 * MILLIMOGS,
-        }
 
-        price
-    }
-    pub fn intrinsic_return(phase_length: usize) -> Balance {
-        let price:Balance;
-        if phase_length == 0 {
-            price = 10;
-        } else if phase_length == 1 {
-            price = 4;
-        } else if phase_length == 2 {
-            price = 2;
-        } else if phase_length == 3 {
-            price = 1;
-        } else {
-            price = 10;
+    /// a price table is valid if it isn't empty, and never divides by zero.
+    pub fn is_valid_table<B: Zero>(price_type: PriceType, table: &[B]) -> bool {
+        if table.is_empty() || table.len() > Self::MAX_TABLE_LENGTH {
+            return false;
+        }
+        match price_type {
+            PriceType::IntrinsicReturn => table.iter().all(|price| !price.is_zero()),
+            _ => true,
         }
-        
-        price
     }
-    pub fn pairing(rarity1: RarityType, rarity2: RarityType) -> Balance {
-        let price:Balance;
-        match rarity1 as u32 + rarity2 as u32 {
-            0 => price =    10 * MILLIMOGS,
-            1 => price =   100 * MILLIMOGS,
-			2 => price =   200 * MILLIMOGS,
-			3 => price =   300 * MILLIMOGS,
-            4 => price =   400 * MILLIMOGS,
-            5 => price =   500 * MILLIMOGS,
-			6 => price =  1000 * MILLIMOGS,
-            7 => price =  1500 * MILLIMOGS,
-            8 => price =  2000 * MILLIMOGS,
-            _ => price = 10000 * MILLIMOGS,
-         }
-
-        price
+
+    /// the price at the index of a table, indices beyond the end use the last entry.
+    pub fn lookup<B: Copy + Zero>(table: &[B], index: usize) -> B {
+        table.get(index).or(table.last()).copied().unwrap_or_else(Zero::zero)
     }
+
+    /// the pairing price of two mogwais, from the pairing price table.
+    pub fn pairing<B: Copy + Zero>(table: &[B], rar1: RarityType, rar2: RarityType) -> B {
+        Self::lookup(table, rar1 as usize + rar2 as usize)
+    }
+
+    pub const MAX_TABLE_LENGTH: usize = 16;
+}
+
+/// The outcome of a breeding, as far as it is known before breeding.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct BreedingPreview<Balance> {
+    /// `None` when the breed type is drawn at hatching.
+    pub breed_type: Option<BreedType>,
+    pub generation: GenerationRange,
+    /// the pairing price, gen 0 mogwais pair for free.
+    pub price: Balance,
 }
 
 pub struct Breeding;
 
 impl Breeding {
 
+    /// preview a breeding with the same rules as `breed_mogwai`, without touching any storage.
+    pub fn preview<B: Copy + Zero>(breed_type: Option<BreedType>, gen1: u32, rar1: RarityType, gen2: u32, rar2: RarityType, effects1: &[AdaptationEffect], effects2: &[AdaptationEffect], pairing_table: &[B]) -> BreedingPreview<B> {
+        BreedingPreview {
+            breed_type,
+            generation: Generation::next_gen_range(gen1, rar1, gen2, rar2, effects1, effects2),
+            price: if gen1 + gen2 == 0 { Zero::zero() } else { Pricing::pairing(pairing_table, rar1, rar2) },
+        }
+    }
+
     pub fn sacrifice(gen1: u32, rar1: u32, metaxy1: Vec<[u8;16]>, gen2: u32, rar2: u32, metaxy2: Vec<[u8;16]>) -> u32 {
         
         let mut result_gen:u32 = 0;
 
+        // without a dna strand or room for a generation jump there is nothing to gain
+        let (dna1, dna2) = match (metaxy1.first(), metaxy2.first()) {
+            (Some(dna1), Some(dna2)) => (dna1, dna2),
+            _ => return result_gen,
+        };
+        if gen2 >= 16 {
+            return result_gen;
+        }
+
         let mut gen_diff:u32 = 0;
         if gen1 > gen2 {
             gen_diff = gen1 - gen2;
@@ -147,19 +224,20 @@ impl Breeding {
 
         } else {
 
-            let mut max_gen:u32 = ((gen_diff * 2) / ((rarity_diff + 1) * rar2)) + 1;
-            if (gen2 + max_gen) > 16 {
+            // rarity_diff > 0 implies rar2 > 0, so the divisors are never zero
+            let mut max_gen:u32 = (gen_diff.saturating_mul(2) / rarity_diff.saturating_add(1).saturating_mul(rar2)).saturating_add(1);
+            if gen2.saturating_add(max_gen) > 16 {
                 max_gen = 16 - gen2;
             }
 
             let prob_aug:u32 = 10;
-            let prob_rar:u32 = rarity_diff * 4;
-            let prob_gen:u32 = gen_diff * 20;
+            let prob_rar:u32 = rarity_diff.saturating_mul(4);
+            let prob_gen:u32 = gen_diff.saturating_mul(20);
 
-            let mut prob:u32 = (256 / (rar2 + prob_rar)) + prob_aug;
+            let mut prob:u32 = (256 / rar2.saturating_add(prob_rar)).saturating_add(prob_aug);
 
-            if prob_gen > prob_rar * 2 {
-                prob += prob_gen - (prob_rar * 2);
+            if prob_gen > prob_rar.saturating_mul(2) {
+                prob = prob.saturating_add(prob_gen - prob_rar.saturating_mul(2));
             }
             
             let mut final_prob:u8 = 255;
@@ -167,12 +245,12 @@ impl Breeding {
                 final_prob = prob as u8;
             }
 
-            let gen_add = gen1 + gen2;
-            let pos1:u8 = metaxy1[0][((gen_add + rar2) % 16) as usize];
-            let pos2:u8 = metaxy2[0][((gen_add + rar1) % 16) as usize];
+            let gen_add = gen1.wrapping_add(gen2);
+            let pos1:u8 = dna1[(gen_add.wrapping_add(rar2) % 16) as usize];
+            let pos2:u8 = dna2[(gen_add.wrapping_add(rar1) % 16) as usize];
 
-            let val1:u8 = metaxy1[0][(pos2 % 16) as usize];
-            let val2:u8 = metaxy2[0][(pos1 % 16) as usize];
+            let val1:u8 = dna1[(pos2 % 16) as usize];
+            let val2:u8 = dna2[(pos1 % 16) as usize];
         
             if val1 < final_prob && val2 < final_prob {
                 result_gen = (val1 as u32 + val2 as u32) % max_gen + 1;  
@@ -182,7 +260,7 @@ impl Breeding {
         result_gen
     }
 
-	pub fn pairing(breed_type: BreedType, gen1: [u8;16], gen2: [u8;16]) -> [u8;32] {
+	pub fn pairing(breed_type: BreedType, gen1: [u8;16], gen2: [u8;16], effects1: &[AdaptationEffect], effects2: &[AdaptationEffect]) -> [u8;32] {
 
 		let mut final_dna : [u8;32] = [0;32];      
 			
@@ -217,6 +295,16 @@ impl Breeding {
 				r2.copy_from_slice(&gen2[8..16]);
 			},
 		}
+
+		// dominant adaptations always pass on the dna of the parent in dominant order
+		if effects1.contains(&AdaptationEffect::Dominant) {
+			l1.copy_from_slice(&gen1[..8]);
+			l2.copy_from_slice(&gen1[8..16]);
+		}
+		if effects2.contains(&AdaptationEffect::Dominant) {
+			r1.copy_from_slice(&gen2[..8]);
+			r2.copy_from_slice(&gen2[8..16]);
+		}
 		return final_dna;
 	}
 
@@ -354,11 +442,58 @@ impl Breeding {
     }
 }
 
+/// The generations and rarities a breeding can result in.
+#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct GenerationRange {
+    pub min_gen: u32,
+    pub max_gen: u32,
+    pub min_rarity: RarityType,
+    pub max_rarity: RarityType,
+}
+
 pub struct Generation { }
 
 impl Generation {
 
-    pub fn next_gen(gen1: u32, rar1: RarityType, gen2: u32, rar2: RarityType, random_hash: &[u8]) -> (RarityType,u32) {
+    /// the range `next_gen` can result in, over all random hashes.
+    pub fn next_gen_range(gen1: u32, rar1: RarityType, gen2: u32, rar2: RarityType, effects1: &[AdaptationEffect], effects2: &[AdaptationEffect]) -> GenerationRange {
+
+        // each random value is only compared to zero or to a threshold, after taking it modulo the
+        // doubled generation, so the smallest and the largest remainder cover every outcome.
+        let remainders = |gen: u32, rar: RarityType| {
+            let rar = (rar as u32).saturating_sub(1);
+            let mut gen_pow2 = gen.saturating_mul(2);
+            if gen_pow2 >= rar * 2 {
+                gen_pow2 -= rar;
+            }
+            [0u8, gen_pow2.saturating_sub(1).min(u8::MAX as u32) as u8]
+        };
+        let remainders = [remainders(gen1, rar1), remainders(gen2, rar2)];
+
+        let mut range: Option<GenerationRange> = None;
+        // the six random values of next_gen, three for each parent
+        for combination in 0..64u32 {
+            let mut random_hash = [0u8; 32];
+            for value in 0..6 {
+                random_hash[1 + 2 * value] = remainders[value / 3][((combination >> value) & 1) as usize];
+            }
+            let (rarity, gen) = Self::next_gen(gen1, rar1, gen2, rar2, &random_hash, effects1, effects2);
+            range = Some(match range {
+                None => GenerationRange { min_gen: gen, max_gen: gen, min_rarity: rarity, max_rarity: rarity },
+                Some(range) => GenerationRange {
+                    min_gen: range.min_gen.min(gen),
+                    max_gen: range.max_gen.max(gen),
+                    min_rarity: RarityType::from_u32((range.min_rarity as u32).min(rarity as u32)),
+                    max_rarity: RarityType::from_u32((range.max_rarity as u32).max(rarity as u32)),
+                },
+            });
+        }
+        range.expect("at least one combination is evaluated; qed")
+    }
+
+    pub fn next_gen(gen1: u32, rar1: RarityType, gen2: u32, rar2: RarityType, random_hash: &[u8], effects1: &[AdaptationEffect], effects2: &[AdaptationEffect]) -> (RarityType,u32) {
         
         let mut result: u32 = 1;
         
@@ -444,18 +579,29 @@ impl Generation {
             }
         }
 
-        let rarity = RarityType::from_u32(((rarity1 + rarity2 + ((rar1 as u32 + rar2 as u32) / 2)) / 2) % 5);
+        let mut rarity_value = ((rarity1 + rarity2 + ((rar1 as u32 + rar2 as u32) / 2)) / 2) % 5;
+
+        // adaptations of the parents boost the offspring
+        for effect in effects1.iter().chain(effects2.iter()) {
+            match effect {
+                AdaptationEffect::RarityBoost => rarity_value = (rarity_value + 1).min(RarityType::Legendary as u32),
+                AdaptationEffect::GenerationBoost => result = (result + 1).min(16),
+                _ => { },
+            }
+        }
+
+        let rarity = RarityType::from_u32(rarity_value);
 
         (rarity, result)
     }
 }
 
-struct Binary { }
+pub(crate) struct Binary { }
 
 impl Binary {
 
     pub fn get_bit_at(input: u8, n: u8) -> bool {
-        input & (1 << n) != 0
+        input & (1 << (n % 8)) != 0
     }
 
     pub fn copy_bits(mut old: u8, mut new: u8, side: usize) -> u8 {
@@ -16,14 +16,18 @@
 mod benchmarking;
 
 use frame_support::{
-	decl_module, decl_error, decl_event, decl_storage, ensure, codec::{Encode, Decode}, dispatch, PalletId,
+	ensure, codec::{Encode, Decode}, dispatch, PalletId, RuntimeDebug,
+	weights::{Weight, DispatchClass},
 	traits::{
-		Get, Randomness, Currency, ReservableCurrency, ExistenceRequirement, WithdrawReasons, OnUnbalanced
+		Get, Randomness, Currency, ReservableCurrency, ExistenceRequirement, WithdrawReasons, OnUnbalanced,
+		EnsureOrigin,
 	}};
-use frame_system::{ensure_signed};
-use sp_runtime::{SaturatedConversion, traits::{Hash, TrailingZeroInput, Zero, AccountIdConversion}};
+use frame_system::{ensure_signed, ensure_root};
+use sp_runtime::{SaturatedConversion, traits::{Hash, TrailingZeroInput, Zero, One, Saturating, CheckedAdd, CheckedSub, CheckedDiv, AccountIdConversion}};
 use sp_std::vec::{Vec};
 use sp_std::prelude::*;
+#[cfg(feature = "std")]
+use serde::{Serialize, Deserialize};
 
 #[cfg(test)]
 mod mock;
@@ -33,19 +37,58 @@ mod tests;
 
 /// Implementations of some helper traits passed into runtime modules as associated types.
 pub mod general;
-use general::{Pricing, Breeding, BreedType, Generation, RarityType, FeeType};
+use general::{Pricing, PriceType, Breeding, BreedType, BreedingPreview, Generation, RarityType, FeeType};
 
 pub mod game_event;
 use game_event::{GameEventType};
 
 pub mod game_config;
-use game_config::{GameConfig};
+use game_config::{GameConfig, GameConfigType, GameFeature, RandomnessMode};
+
+mod migration;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+pub mod adaptation;
+use adaptation::{Adaptation, AdaptationTrigger, AdaptationEffect};
+
+pub mod lifecycle;
+use lifecycle::MogwaiLock;
+
+mod nonfungibles;
 
 const MAX_AUCTIONS_PER_BLOCK: usize = 2;
+const MAX_BIDDERS_PER_AUCTION: usize = 16;
 const MAX_EVENTS_PER_BLOCK: usize = 10;
+const MAX_ADAPTATIONS: usize = 32;
+const MAX_MINT_BATCH: usize = 32;
+const MAX_GENERATION: u32 = 16;
+const MAX_REAP_KEYS: usize = 16;
+const MAX_SWEEP_PER_BLOCK: u32 = 8;
+const SWEEP_PAUSE_BLOCKS: u32 = 600;
+
+/// The storage layout releases of the pallet.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum Releases {
+	/// account configurations stored as raw `Vec<u8>` parameters.
+	V1_0_0,
+	/// account configurations stored as typed `GameConfig`.
+	V2_0_0,
+	/// storage owned by the `#[frame_support::pallet]` macro, under the pallet name of the runtime.
+	V3_0_0,
+	/// mogwai bios record the entropy mixed in at hatching.
+	V4_0_0,
+	/// hatching mixes in the entropy of the hatch block, upgraded chains keep the legacy hatching.
+	V5_0_0,
+	/// mogwais listed, auctioned or incubating carry a lifecycle lock.
+	V6_0_0,
+}
+
+impl Default for Releases { fn default() -> Self { Self::V1_0_0 } }
 
 #[derive(Encode, Decode, Default, Clone, PartialEq)]
-#[cfg_attr(feature = "std", derive(Debug))]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
 pub struct MogwaiStruct<Hash, BlockNumber, Balance, RarityType> {
 	id: Hash,
 	dna: Hash,
@@ -56,7 +99,7 @@ pub struct MogwaiStruct<Hash, BlockNumber, Balance, RarityType> {
 }
 
 #[derive(Encode, Decode, Default, Clone, PartialEq)]
-#[cfg_attr(feature = "std", derive(Debug))]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
 pub struct MogwaiBios<Hash, BlockNumber, Balance> {
 	mogwai_id: Hash,
 	state: u32,
@@ -65,6 +108,8 @@ pub struct MogwaiBios<Hash, BlockNumber, Balance> {
 	level: u8,
 	phases: Vec<BlockNumber>,
 	adaptations: Vec<Hash>,
+	/// the entropy the metaxy was segmented with, `None` for mogwais hatched before it was recorded.
+	entropy: Option<Hash>,
 }
 
 //#[derive(Encode, Decode, Default, Clone, PartialEq)]
@@ -74,7 +119,7 @@ pub struct MogwaiBios<Hash, BlockNumber, Balance> {
 //}
 
 #[derive(Encode, Decode, Default, Clone, PartialEq)]
-#[cfg_attr(feature = "std", derive(Debug))]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
 pub struct GameEvent<Hash, BlockNumber, GameEventType> {
 	id: Hash,
 	begin: BlockNumber,
@@ -85,7 +130,7 @@ pub struct GameEvent<Hash, BlockNumber, GameEventType> {
 }
 
 #[derive(Encode, Decode, Default, Clone, PartialEq)]
-#[cfg_attr(feature = "std", derive(Debug))]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
 pub struct Auction<Hash, Balance, BlockNumber, AccountId> {
 	mogwai_id: Hash,
 	mogwai_owner: AccountId,
@@ -95,168 +140,530 @@ pub struct Auction<Hash, Balance, BlockNumber, AccountId> {
 	high_bidder: AccountId,
 }
 
+/// The parents of a mogwai bred in future block mode, the outcome is drawn when it hatches.
+#[derive(Encode, Decode, Default, Clone, PartialEq, RuntimeDebug)]
+pub struct PendingBreed<Hash> {
+	parents: [Hash; 2],
+	gens: [u32; 2],
+	rarities: [RarityType; 2],
+	/// the dna strands passed on by the parents.
+	strands: [[u8; 16]; 2],
+	effects: [Vec<AdaptationEffect>; 2],
+}
+
+/// Everything known about a mogwai, gathered for a single runtime api query.
+#[derive(Encode, Decode, Clone, PartialEq)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct MogwaiFull<AccountId, Hash, BlockNumber, Balance> {
+	pub mogwai: MogwaiStruct<Hash, BlockNumber, Balance, RarityType>,
+	/// `None` until the mogwai has hatched.
+	pub bios: Option<MogwaiBios<Hash, BlockNumber, Balance>>,
+	pub owner: AccountId,
+	pub stashed: bool,
+	pub name: Option<Vec<u8>>,
+	pub auction: Option<Auction<Hash, Balance, BlockNumber, AccountId>>,
+	pub lock: Option<MogwaiLock>,
+	pub pending_events: Vec<GameEvent<Hash, BlockNumber, GameEventType>>,
+}
+
+/// A mogwai preloaded at genesis, for test networks and demos.
+#[derive(Clone, PartialEq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct GenesisMogwai<AccountId, Hash, Balance, BlockNumber> {
+	pub owner: AccountId,
+	pub dna: Hash,
+	pub gen: u32,
+	pub rarity: RarityType,
+	/// hatch the mogwai at genesis, so it starts with a bios, required above generation 0.
+	pub with_bios: bool,
+	/// open an auction for the mogwai, with the minimum bid and the expiry block.
+	pub auction: Option<(Balance, BlockNumber)>,
+}
+
 type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::NegativeImbalance;
 
-/// Configure the pallet by specifying the parameters and types on which it depends.
-pub trait Config: frame_system::Config {
-	
-	/// The dotmog's module id, is used for deriving its mogwai account ID's.
-	type PalletId: Get<PalletId>;
+pub use pallet::*;
 
-	/// The overarching event type.
-	type Event: From<Event<Self>> + Into<<Self as frame_system::Config>::Event>;
+#[frame_support::pallet]
+pub mod pallet {
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+	use super::*;
 
-	/// The currency mechanism.
-	type Currency: ReservableCurrency<Self::AccountId>;
+	/// Configure the pallet by specifying the parameters and types on which it depends.
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
 
-	/// Something that provides randomness in the runtime.
-	type Randomness: Randomness<Self::Hash, Self::BlockNumber>;
+		/// The dotmog's module id, is used for deriving its mogwai account ID's.
+		type PalletId: Get<PalletId>;
 
-	/// Handler for price payments.
-	type PricePayment: OnUnbalanced<NegativeImbalanceOf<Self>>;
+		/// The overarching event type.
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
 
-	// Weight information for extrinsics in this pallet.
-	//type WeightInfo: WeightInfo;
-}
+		/// The currency mechanism.
+		type Currency: ReservableCurrency<Self::AccountId>;
 
-// The pallet's runtime storage items.
-// https://substrate.dev/docs/en/knowledgebase/runtime/storage
-decl_storage! {
-	// A unique name is used to ensure that the pallet's storage items are isolated.
-	// This name may be updated, but each pallet in the runtime must use a unique name.
-	// ---------------------------------vvvvvvvvvvvvvv
-	trait Store for Module<T: Config> as DotMogModule {
+		/// Something that provides randomness in the runtime.
+		type Randomness: Randomness<Self::Hash, Self::BlockNumber>;
 
-		// Learn more about declaring storage items:
-		// https://substrate.dev/docs/en/knowledgebase/runtime/storage#declaring-storage-items
-		Something get(fn something): Option<u32>;
+		/// Handler for price payments.
+		type PricePayment: OnUnbalanced<NegativeImbalanceOf<Self>>;
 
-		/// The `AccountId` of the dot mog founder.
-		Key get(fn key) config(): T::AccountId;
+		/// The minimum amount a mogwai can be fed with.
+		type MinFeedAmount: Get<BalanceOf<Self>>;
 
-		/// A map of the current configuration of an account.
-		AccountConfig get(fn account_config): map hasher(blake2_128_concat) T::AccountId => Option<Vec<u8>>;
+		/// The maximum intrinsic value a mogwai can hold per level.
+		type MaxIntrinsicPerLevel: Get<BalanceOf<Self>>;
 
-		/// A map of mogwais accessible by the mogwai hash.
-		Mogwais get(fn mogwai): map hasher(identity) T::Hash => MogwaiStruct<T::Hash, T::BlockNumber, BalanceOf<T>, RarityType>;
-		/// A map of mogwai bios accessible by the mogwai hash.
-		MogwaisBios get(fn mogwai_bios): map hasher(identity) T::Hash => MogwaiBios<T::Hash, T::BlockNumber, BalanceOf<T>>;
-		/// A map of mogwai owners accessible by the mogwai hash.
-		MogwaiOwner get(fn owner_of): map hasher(identity) T::Hash => Option<T::AccountId>;
-				
-		/// A map of all existing mogwais accessible by the index. 
-		AllMogwaisArray get(fn mogwai_by_index): map hasher(blake2_128_concat) u64 => T::Hash;
-		/// A count over all existing mogwais in the system.
-		AllMogwaisCount get(fn all_mogwais_count): u64;
-		/// A map of the index of the mogwai accessible by the mogwai hash.
-		AllMogwaisIndex: map hasher(identity) T::Hash => u64;
-		
-		/// A map of all mogwai hashes associated with an account.
-		OwnedMogwaisArray get(fn mogwai_of_owner_by_index): map hasher(blake2_128_concat) (T::AccountId, u64) => T::Hash;
-		/// A count over all existing mogwais owned by one account.
-		OwnedMogwaisCount get(fn owned_mogwais_count): map hasher(blake2_128_concat) T::AccountId => u64;
-		/// A map of the owned mogwais index accessible by the mogwai hash.
-		OwnedMogwaisIndex: map hasher(identity) T::Hash => u64;
-		
-		/// A map of mogwai auctions accessible by the mogwai hash.
-		MogwaiAuction get(fn auction_of): map hasher(blake2_128_concat) T::Hash => Option<Auction<T::Hash, BalanceOf<T>, T::BlockNumber, T::AccountId>>;
-		/// A vec of mogwai auctions accessible by the expiry block number. 
-		Auctions get(fn auctions_expire_at): map hasher(blake2_128_concat) T::BlockNumber => Vec<Auction<T::Hash, BalanceOf<T>, T::BlockNumber, T::AccountId>>;
-		/// Current auction period max limit.       
-		AuctionPeriodLimit get(fn auction_period_limit): T::BlockNumber = (1000 as u32).into();
-		
-		/// A map of bids accessible by account id and mogwai hash.
-		Bids get(fn bid_of): map hasher(blake2_128_concat) (T::Hash, T::AccountId) => BalanceOf<T>;
-		/// A vec of accounts accessible by mogwai hash.
-		BidAccounts get(fn bid_accounts): map hasher(blake2_128_concat) T::Hash => Vec<T::AccountId>;
+		/// The deposit reserved for registering an account name.
+		type AccountNameDeposit: Get<BalanceOf<Self>>;
 
-		/// A map of game events accessible by the game event id (hash).
-		GameEvents get(fn game_events): map hasher(identity) T::Hash => GameEvent<T::Hash, T::BlockNumber, GameEventType>;
+		/// The minimum length of a name.
+		type MinNameLength: Get<u32>;
 
-		/// A map of all existing game events accessible by the index. 
-		AllGameEventsArray get(fn game_event_by_index): map hasher(blake2_128_concat) u64 => T::Hash;
-		/// A count over all existing game events in the system.
-		AllGameEventsCount get(fn all_game_events_count): u64;
-		/// A map of the index of the game events accessible by the game event id (hash).
-		AllGameEventsIndex: map hasher(identity) T::Hash => u64;
+		/// The maximum length of a name.
+		type MaxNameLength: Get<u32>;
 
-		/// A map of all game event ids (hash) associated with an game event type (indexed).
-		GameEventsArray get(fn game_event_of_type_by_index): map hasher(blake2_128_concat) (GameEventType, u64) => T::Hash;
-		/// A count over all existing game events of one particular game event type.
-		GameEventsCount get(fn game_event_of_type_count): map hasher(blake2_128_concat) GameEventType => u64;
-		/// A map of the game event type index accessible by the game event id (hash).
-		GameEventsIndex: map hasher(identity) T::Hash => u64;
+		/// The deposit reserved for naming a mogwai.
+		type MogwaiNameDeposit: Get<BalanceOf<Self>>;
 
-		/// A vec of game event ids (hash) accessible by the triggering block number.
-		GameEventsAtBlock get(fn game_events_at_block): map hasher(blake2_128_concat) T::BlockNumber => Vec<T::Hash>;	
+		/// Enforce that mogwai names are unique.
+		type UniqueMogwaiNames: Get<bool>;
 
-		/// A vec of game event ids (hash) accessible by the corresponding mogwai.
-		GameEventsOfMogwai get(fn game_events_of_mogwai): map hasher(identity) T::Hash => Vec<T::Hash>;
+		/// The reward for each stale entry removed with `reap_stale`.
+		type ReapReward: Get<BalanceOf<Self>>;
 
-		/// The nonce used for randomness.
-		Nonce: u64 = 0;
+		/// The origin allowed to administrate the game, like activation, features, pricing and adaptations.
+		type AdminOrigin: EnsureOrigin<Self::Origin>;
+
+		/// The origin allowed to moderate player content, like offensive names.
+		type ModeratorOrigin: EnsureOrigin<Self::Origin>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
 	}
-}
 
-// Pallets use events to inform users when important changes are made.
-// https://substrate.dev/docs/en/knowledgebase/runtime/events
-decl_event!(
-	pub enum Event<T> where 
-		<T as frame_system::Config>::AccountId,	
-		<T as frame_system::Config>::Hash,
-		<T as frame_system::Config>::BlockNumber,
-		Balance = BalanceOf<T>,
-	{
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	pub struct Pallet<T>(PhantomData<T>);
+
+	/// The `AccountId` of the dot mog founder.
+	#[pallet::storage]
+	#[pallet::getter(fn key)]
+	pub type Key<T: Config> = StorageValue<_, T::AccountId, ValueQuery>;
+
+	/// The `AccountId` receiving the game payments, falls back to the founder if not set.
+	#[pallet::storage]
+	#[pallet::getter(fn treasury)]
+	pub type Treasury<T: Config> = StorageValue<_, T::AccountId, OptionQuery>;
+
+	/// A map of the current configuration of an account.
+	#[pallet::storage]
+	#[pallet::getter(fn account_config)]
+	pub type AccountConfig<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, GameConfig, OptionQuery>;
+
+	/// A map of the registered name and its reserved deposit accessible by the account.
+	#[pallet::storage]
+	#[pallet::getter(fn account_name)]
+	pub type AccountName<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, (Vec<u8>, BalanceOf<T>), OptionQuery>;
+
+	/// A map of the account accessible by its registered name.
+	#[pallet::storage]
+	#[pallet::getter(fn account_of_name)]
+	pub type AccountOfName<T: Config> = StorageMap<_, Blake2_128Concat, Vec<u8>, T::AccountId, OptionQuery>;
+
+	/// A map of mogwais accessible by the mogwai hash.
+	#[pallet::storage]
+	#[pallet::getter(fn mogwai)]
+	pub type Mogwais<T: Config> = StorageMap<_, Identity, T::Hash, MogwaiStruct<T::Hash, T::BlockNumber, BalanceOf<T>, RarityType>, ValueQuery>;
+
+	/// A map of mogwai bios accessible by the mogwai hash.
+	#[pallet::storage]
+	#[pallet::getter(fn mogwai_bios)]
+	pub type MogwaisBios<T: Config> = StorageMap<_, Identity, T::Hash, MogwaiBios<T::Hash, T::BlockNumber, BalanceOf<T>>, ValueQuery>;
+
+	/// A map of mogwai owners accessible by the mogwai hash.
+	#[pallet::storage]
+	#[pallet::getter(fn owner_of)]
+	pub type MogwaiOwner<T: Config> = StorageMap<_, Identity, T::Hash, T::AccountId, OptionQuery>;
+
+	/// The lock of a mogwai, mogwais without one are free to be used.
+	#[pallet::storage]
+	#[pallet::getter(fn lock_of)]
+	pub type MogwaiLocks<T: Config> = StorageMap<_, Identity, T::Hash, MogwaiLock, OptionQuery>;
+
+	/// A map of the mogwai name and the deposit reserved by its owner accessible by the mogwai hash.
+	#[pallet::storage]
+	#[pallet::getter(fn mogwai_name)]
+	pub type MogwaiName<T: Config> = StorageMap<_, Identity, T::Hash, (Vec<u8>, BalanceOf<T>), OptionQuery>;
+
+	/// A map of the mogwai accessible by its name, only maintained when names are unique.
+	#[pallet::storage]
+	#[pallet::getter(fn mogwai_of_name)]
+	pub type MogwaiOfName<T: Config> = StorageMap<_, Blake2_128Concat, Vec<u8>, T::Hash, OptionQuery>;
+
+	/// A map of all existing mogwais accessible by the index.
+	#[pallet::storage]
+	#[pallet::getter(fn mogwai_by_index)]
+	pub type AllMogwaisArray<T: Config> = StorageMap<_, Blake2_128Concat, u64, T::Hash, ValueQuery>;
+
+	/// A count over all existing mogwais in the system.
+	#[pallet::storage]
+	#[pallet::getter(fn all_mogwais_count)]
+	pub type AllMogwaisCount<T> = StorageValue<_, u64, ValueQuery>;
+
+	/// A map of the index of the mogwai accessible by the mogwai hash.
+	#[pallet::storage]
+	pub(super) type AllMogwaisIndex<T: Config> = StorageMap<_, Identity, T::Hash, u64, ValueQuery>;
+
+	/// A map of all mogwai hashes associated with an account.
+	#[pallet::storage]
+	#[pallet::getter(fn mogwai_of_owner_by_index)]
+	pub type OwnedMogwaisArray<T: Config> = StorageMap<_, Blake2_128Concat, (T::AccountId, u64), T::Hash, ValueQuery>;
+
+	/// A count over all existing mogwais owned by one account.
+	#[pallet::storage]
+	#[pallet::getter(fn owned_mogwais_count)]
+	pub type OwnedMogwaisCount<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u64, ValueQuery>;
+
+	/// A map of the owned mogwais index accessible by the mogwai hash.
+	#[pallet::storage]
+	pub(super) type OwnedMogwaisIndex<T: Config> = StorageMap<_, Identity, T::Hash, u64, ValueQuery>;
+
+	/// A map of all mogwai hashes in the stash of an account.
+	#[pallet::storage]
+	#[pallet::getter(fn mogwai_of_stash_by_index)]
+	pub type StashedMogwaisArray<T: Config> = StorageMap<_, Blake2_128Concat, (T::AccountId, u64), T::Hash, ValueQuery>;
+
+	/// A count over all mogwais in the stash of one account.
+	#[pallet::storage]
+	#[pallet::getter(fn stashed_mogwais_count)]
+	pub type StashedMogwaisCount<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u64, ValueQuery>;
+
+	/// A map of the stashed mogwais index accessible by the mogwai hash.
+	#[pallet::storage]
+	pub(super) type StashedMogwaisIndex<T: Config> = StorageMap<_, Identity, T::Hash, u64, ValueQuery>;
+
+	/// A map of mogwai auctions accessible by the mogwai hash.
+	#[pallet::storage]
+	#[pallet::getter(fn auction_of)]
+	pub type MogwaiAuction<T: Config> = StorageMap<_, Blake2_128Concat, T::Hash, Auction<T::Hash, BalanceOf<T>, T::BlockNumber, T::AccountId>, OptionQuery>;
+
+	/// A vec of mogwai auctions accessible by the expiry block number.
+	#[pallet::storage]
+	#[pallet::getter(fn auctions_expire_at)]
+	pub type Auctions<T: Config> = StorageMap<_, Blake2_128Concat, T::BlockNumber, Vec<Auction<T::Hash, BalanceOf<T>, T::BlockNumber, T::AccountId>>, ValueQuery>;
+
+	#[pallet::type_value]
+	pub fn DefaultAuctionPeriodLimit<T: Config>() -> T::BlockNumber { (1000 as u32).into() }
+
+	/// Current auction period max limit.
+	#[pallet::storage]
+	#[pallet::getter(fn auction_period_limit)]
+	pub type AuctionPeriodLimit<T: Config> = StorageValue<_, T::BlockNumber, ValueQuery, DefaultAuctionPeriodLimit<T>>;
+
+	/// A map of bids accessible by account id and mogwai hash.
+	#[pallet::storage]
+	#[pallet::getter(fn bid_of)]
+	pub type Bids<T: Config> = StorageMap<_, Blake2_128Concat, (T::Hash, T::AccountId), BalanceOf<T>, ValueQuery>;
+
+	/// A vec of accounts accessible by mogwai hash.
+	#[pallet::storage]
+	#[pallet::getter(fn bid_accounts)]
+	pub type BidAccounts<T: Config> = StorageMap<_, Blake2_128Concat, T::Hash, Vec<T::AccountId>, ValueQuery>;
+
+	/// A map of game events accessible by the game event id (hash).
+	#[pallet::storage]
+	#[pallet::getter(fn game_events)]
+	pub type GameEvents<T: Config> = StorageMap<_, Identity, T::Hash, GameEvent<T::Hash, T::BlockNumber, GameEventType>, ValueQuery>;
+
+	/// A map of all existing game events accessible by the index.
+	#[pallet::storage]
+	#[pallet::getter(fn game_event_by_index)]
+	pub type AllGameEventsArray<T: Config> = StorageMap<_, Blake2_128Concat, u64, T::Hash, ValueQuery>;
+
+	/// A count over all existing game events in the system.
+	#[pallet::storage]
+	#[pallet::getter(fn all_game_events_count)]
+	pub type AllGameEventsCount<T> = StorageValue<_, u64, ValueQuery>;
+
+	/// A map of the index of the game events accessible by the game event id (hash).
+	#[pallet::storage]
+	pub(super) type AllGameEventsIndex<T: Config> = StorageMap<_, Identity, T::Hash, u64, ValueQuery>;
+
+	/// A map of all game event ids (hash) associated with an game event type (indexed).
+	#[pallet::storage]
+	#[pallet::getter(fn game_event_of_type_by_index)]
+	pub type GameEventsArray<T: Config> = StorageMap<_, Blake2_128Concat, (GameEventType, u64), T::Hash, ValueQuery>;
+
+	/// A count over all existing game events of one particular game event type.
+	#[pallet::storage]
+	#[pallet::getter(fn game_event_of_type_count)]
+	pub type GameEventsCount<T> = StorageMap<_, Blake2_128Concat, GameEventType, u64, ValueQuery>;
+
+	/// A map of the game event type index accessible by the game event id (hash).
+	#[pallet::storage]
+	pub(super) type GameEventsIndex<T: Config> = StorageMap<_, Identity, T::Hash, u64, ValueQuery>;
+
+	/// A vec of game event ids (hash) accessible by the triggering block number.
+	#[pallet::storage]
+	#[pallet::getter(fn game_events_at_block)]
+	pub type GameEventsAtBlock<T: Config> = StorageMap<_, Blake2_128Concat, T::BlockNumber, Vec<T::Hash>, ValueQuery>;
+
+	/// A vec of game event ids (hash) accessible by the corresponding mogwai.
+	#[pallet::storage]
+	#[pallet::getter(fn game_events_of_mogwai)]
+	pub type GameEventsOfMogwai<T: Config> = StorageMap<_, Identity, T::Hash, Vec<T::Hash>, ValueQuery>;
+
+	/// A map of adaptation definitions accessible by the adaptation id (hash).
+	#[pallet::storage]
+	#[pallet::getter(fn adaptation)]
+	pub type Adaptations<T: Config> = StorageMap<_, Identity, T::Hash, Adaptation<T::Hash>, OptionQuery>;
+
+	/// A vec of all defined adaptation ids (hash).
+	#[pallet::storage]
+	#[pallet::getter(fn adaptation_ids)]
+	pub type AdaptationIds<T: Config> = StorageValue<_, Vec<T::Hash>, ValueQuery>;
+
+	/// The nonce used for randomness.
+	#[pallet::storage]
+	pub(super) type Nonce<T> = StorageValue<_, u64, ValueQuery>;
+
+	#[pallet::type_value]
+	pub fn DefaultGameActivated() -> bool { true }
+
+	/// Global switch, breeding, morphing, marketplace and auctions are only available when activated.
+	#[pallet::storage]
+	#[pallet::getter(fn game_activated)]
+	pub type GameActivated<T> = StorageValue<_, bool, ValueQuery, DefaultGameActivated>;
+
+	/// The price tables accessible by the price type, empty tables fall back to the defaults.
+	#[pallet::storage]
+	#[pallet::getter(fn price_table)]
+	pub type PriceTables<T: Config> = StorageMap<_, Blake2_128Concat, PriceType, Vec<BalanceOf<T>>, ValueQuery>;
+
+	/// Bitflags of the game features that are currently disabled.
+	#[pallet::storage]
+	#[pallet::getter(fn disabled_features)]
+	pub type DisabledFeatures<T> = StorageValue<_, u8, ValueQuery>;
+
+	/// The way the randomness of a breeding is drawn.
+	#[pallet::storage]
+	#[pallet::getter(fn randomness_mode)]
+	pub type BreedingRandomness<T> = StorageValue<_, RandomnessMode, ValueQuery>;
+
+	/// The parents of the mogwais waiting for their breeding outcome to be drawn at hatching.
+	#[pallet::storage]
+	#[pallet::getter(fn pending_breed)]
+	pub type PendingBreeds<T: Config> = StorageMap<_, Identity, T::Hash, PendingBreed<T::Hash>, OptionQuery>;
+
+	/// Segment hatched mogwais with the hash of their genesis block, like before the hatch entropy.
+	#[pallet::storage]
+	#[pallet::getter(fn legacy_hatching)]
+	pub type LegacyHatching<T> = StorageValue<_, bool, ValueQuery>;
+
+	/// The block of the upgrade to the hatch entropy, mogwais bred before it keep the legacy hatching.
+	#[pallet::storage]
+	#[pallet::getter(fn legacy_hatching_before)]
+	pub type LegacyHatchingBefore<T: Config> = StorageValue<_, T::BlockNumber, OptionQuery>;
+
+	/// The map and the raw key the cleanup pass stopped at, with the entries reaped in the current cycle.
+	#[pallet::storage]
+	#[pallet::getter(fn sweep_cursor)]
+	pub(super) type SweepCursor<T> = StorageValue<_, (u8, Vec<u8>, u32), ValueQuery>;
+
+	/// The cleanup pass rests until this block, once a full cycle over all maps found nothing stale.
+	#[pallet::storage]
+	#[pallet::getter(fn sweep_paused_until)]
+	pub(super) type SweepPausedUntil<T: Config> = StorageValue<_, T::BlockNumber, ValueQuery>;
+
+	/// The current storage layout release, new chains start with the latest one.
+	#[pallet::storage]
+	#[pallet::getter(fn storage_version)]
+	pub type StorageVersion<T> = StorageValue<_, Releases, ValueQuery>;
+
+	#[pallet::genesis_config]
+	pub struct GenesisConfig<T: Config> {
+		/// The `AccountId` of the dot mog founder.
+		pub key: T::AccountId,
+		/// price tables overriding the defaults.
+		pub price_tables: Vec<(PriceType, Vec<BalanceOf<T>>)>,
+		/// upgraded account configurations.
+		pub account_configs: Vec<(T::AccountId, GameConfig)>,
+		/// mogwais minted into the owner accounts, the account configurations apply.
+		pub mogwais: Vec<GenesisMogwai<T::AccountId, T::Hash, BalanceOf<T>, T::BlockNumber>>,
+		/// the way the randomness of a breeding is drawn.
+		pub randomness_mode: RandomnessMode,
+	}
+
+	#[cfg(feature = "std")]
+	impl<T: Config> Default for GenesisConfig<T> {
+		fn default() -> Self {
+			Self {
+				key: Default::default(),
+				price_tables: Vec::new(),
+				account_configs: Vec::new(),
+				mogwais: Vec::new(),
+				randomness_mode: Default::default(),
+			}
+		}
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+		fn build(&self) {
+			<Key<T>>::put(&self.key);
+			StorageVersion::<T>::put(Releases::V6_0_0);
+			BreedingRandomness::<T>::put(self.randomness_mode);
+
+			for price_type in PriceType::ALL.iter() {
+				let table = match self.price_tables.iter().find(|(t, _)| t == price_type) {
+					Some((_, table)) => table.clone(),
+					None => Pricing::default_table(*price_type),
+				};
+				assert!(Pricing::is_valid_table(*price_type, &table), "invalid genesis price table");
+				<PriceTables<T>>::insert(price_type, table);
+			}
+
+			for (account, game_config) in self.account_configs.iter() {
+				assert!(
+					[GameConfigType::MaxMogwaisInAccount, GameConfigType::MaxStashSize, GameConfigType::AccountNaming].iter()
+						.all(|config_type| game_config.get(*config_type) <= GameConfig::max_value(*config_type)),
+					"invalid genesis account config"
+				);
+				<AccountConfig<T>>::insert(account, game_config);
+			}
+
+			for mogwai in self.mogwais.iter() {
+				assert!(mogwai.gen <= MAX_GENERATION, "invalid genesis mogwai generation");
+				assert!(mogwai.with_bios || mogwai.gen == 0, "genesis mogwai above generation 0 needs a bios");
+				let mogwai_id = <Pallet<T>>::mint_specific(mogwai.owner.clone(), mogwai.dna, mogwai.gen, mogwai.rarity, mogwai.with_bios)
+					.expect("genesis mogwai owner has no space left");
+
+				if let Some((min_bid, expiry)) = mogwai.auction {
+					assert!(!<Pallet<T>>::is_stashed(mogwai_id), "genesis mogwai in stash can't be auctioned");
+					assert!(expiry > Zero::zero() && expiry <= <Pallet<T>>::auction_period_limit(), "invalid genesis auction expiry");
+					assert!(<Pallet<T>>::auctions_expire_at(expiry).len() < MAX_AUCTIONS_PER_BLOCK, "too many genesis auctions at expiry");
+					let auction = Auction {
+						mogwai_id,
+						mogwai_owner: mogwai.owner.clone(),
+						expiry,
+						min_bid,
+						high_bid: min_bid,
+						high_bidder: mogwai.owner.clone(),
+					};
+					<MogwaiAuction<T>>::insert(mogwai_id, &auction);
+					<Auctions<T>>::mutate(expiry, |auctions| auctions.push(auction));
+					<MogwaiLocks<T>>::insert(mogwai_id, MogwaiLock::Auctioned);
+				}
+			}
+		}
+	}
+
+	// Pallets use events to inform users when important changes are made.
+	// https://substrate.dev/docs/en/knowledgebase/runtime/events
+	#[pallet::event]
+	#[pallet::metadata(T::AccountId = "AccountId", T::Hash = "Hash", T::BlockNumber = "BlockNumber", BalanceOf<T> = "Balance")]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+
+		/// The dot mog founder key has been changed.
+		KeyChanged(T::AccountId),
 
-		/// Event documentation should end with an array that provides descriptive names for event
-		/// parameters. [something, who]
-		SomethingStored(u32, AccountId),
+		/// The treasury receiving the game payments has been changed.
+		TreasuryChanged(Option<T::AccountId>),
 
-		// A dot mog sudo just took place.
-		//DotMogDid(DispatchResult),
+		/// A account configuration has been changed.
+		AccountConfigChanged(T::AccountId, GameConfigType, u8),
 
-		// A account configuration has been changed.
-		AccountConfigChanged(AccountId, Vec<u8>),
+		/// A name has been registered for an account.
+		AccountNameSet(T::AccountId, Vec<u8>),
+
+		/// The name of an account has been cleared.
+		AccountNameCleared(T::AccountId),
 
 		/// A mogwai has been created.
-		MogwaiCreated(AccountId, Hash),
+		MogwaiCreated(T::AccountId, T::Hash),
+
+		/// A mogwai has been named.
+		MogwaiNamed(T::AccountId, T::Hash, Vec<u8>),
+
+		/// The name of a mogwai has been dropped, its new owner couldn't reserve the deposit.
+		MogwaiNameCleared(T::Hash),
 
 		/// A mogwai has been removed. (R.I.P.)
-		MogwaiRemoved(AccountId, Hash),
+		MogwaiRemoved(T::AccountId, T::Hash),
 
 		/// A price has been set for a mogwai.
-		PriceSet(AccountId, Hash, Balance),
+		PriceSet(T::AccountId, T::Hash, BalanceOf<T>),
+
+		/// A mogwai has been moved into the stash.
+		MogwaiStashed(T::AccountId, T::Hash),
+
+		/// A mogwai has been moved back from the stash.
+		MogwaiUnstashed(T::AccountId, T::Hash),
 
 		/// A mogwai changed his owner.
-		Transferred(AccountId, AccountId, Hash),
+		Transferred(T::AccountId, T::AccountId, T::Hash),
 
 		/// A mogwai has been was bought.
-		Bought(AccountId, AccountId, Hash, Balance),
+		Bought(T::AccountId, T::AccountId, T::Hash, BalanceOf<T>),
 
 		/// A auction has been created
-		AuctionCreated(Hash, Balance, BlockNumber),
+		AuctionCreated(T::Hash, BalanceOf<T>, T::BlockNumber),
 
 		/// A bid has been placed.
-		Bid(Hash, Balance, AccountId),
+		Bid(T::Hash, BalanceOf<T>, T::AccountId),
 
 		/// A auction hash been finalized.
-		AuctionFinalized(Hash, Balance, BlockNumber),
+		AuctionFinalized(T::Hash, BalanceOf<T>, T::BlockNumber),
+
+		/// An auction has been canceled, all bids are returned.
+		AuctionCanceled(T::Hash),
 
 		/// A game event hash been created.
-		GameEventCreated(AccountId, Hash),
+		GameEventCreated(T::AccountId, T::Hash),
 
 		/// A game event hash been executed.
-		GameEventExecuted(Hash),
-	}
-);
+		GameEventExecuted(T::Hash),
+
+		/// The next growth phase of a mogwai couldn't be scheduled. [mogwai, phase]
+		GrowthStalled(T::Hash, GameEventType),
 
-// Errors inform users that something went wrong.
-decl_error! {
-	pub enum Error for Module<T: Config> {
+		/// A mogwai has been fed, increasing its intrinsic value.
+		MogwaiFed(T::AccountId, T::Hash, BalanceOf<T>),
 
-		/// Error names should be descriptive.
-		NoneValue,
+		/// The game has been activated or deactivated.
+		GameActivationChanged(bool),
+
+		/// A price table has been changed.
+		PricingChanged(PriceType, Vec<BalanceOf<T>>),
+
+		/// A game feature has been enabled or disabled.
+		FeatureStatusChanged(GameFeature, bool),
+
+		/// The way the randomness of a breeding is drawn has been changed.
+		RandomnessModeChanged(RandomnessMode),
+
+		/// The legacy hatching has been enabled or disabled.
+		LegacyHatchingChanged(bool),
+
+		/// A new adaptation has been defined.
+		AdaptationAdded(T::Hash),
+
+		/// A mogwai gained an adaptation. [mogwai, adaptation]
+		AdaptationGained(T::Hash, T::Hash),
+
+		/// Stale entries have been reaped. [reaper, entries, reward]
+		StaleReaped(T::AccountId, u32, BalanceOf<T>),
+	}
+
+	// Errors inform users that something went wrong.
+	#[pallet::error]
+	pub enum Error<T> {
 
 		// Sender must be the dot mog sudo account
 		//RequireDotMogSudo,
@@ -276,14 +683,41 @@ decl_error! {
 		/// The mogwai isn't owned by the sender.
 		MogwaiNotOwned,
 
+		/// The mogwai is locked for a sale, an auction or its hatching.
+		MogwaiLocked,
+
+		/// The mogwai isn't listed for a sale.
+		MogwaiNotListed,
+
 		/// Same mogwai choosen for extrinsic.
 		MogwaiSame,
 
 		/// Maximum Mogwais in account reached.
 		MaxMogwaisInAccount,
 
-		/// The submitted index is out of range.
-		ConfigIndexOutOfRange,
+		/// Maximum Mogwais in stash reached.
+		MaxStashSize,
+
+		/// Too many mogwais minted at once.
+		MaxMintBatchSize,
+
+		/// Too many mogwais reaped at once.
+		MaxReapKeys,
+
+		/// Maximum number of bidders on the auction reached.
+		MaxBiddersReached,
+
+		/// None of the mogwais left stale entries behind.
+		NothingStale,
+
+		/// The generation is out of range.
+		GenerationInvalid,
+
+		/// The mogwai is in the stash.
+		MogwaiStashed,
+
+		/// The mogwai isn't in the stash.
+		MogwaiNotStashed,
 
 		/// Invalid or unimplemented config update.
 		ConfigUpdateInvalid,
@@ -294,132 +728,284 @@ decl_error! {
 		// Mogwai doesn't have a bios code.
 		MogwaiHasNoBios,
 
+		/// Mogwais above generation 0 can't be minted without a bios, they could neither breed nor be sacrificed.
+		MintWithoutBios,
+
 		/// The game event id (hash) already exists.
 		GameEventAlreadyExists,
+
+		/// The amount is lower than the minimum feeding amount.
+		FeedAmountTooLow,
+
+		/// The feeding exceeds the intrinsic value cap of the mogwai level.
+		MaxIntrinsicReached,
+
+		/// The intrinsic value of the mogwai would overflow.
+		IntrinsicOverflow,
+
+		/// The intrinsic return for the mogwai phase is invalid.
+		IntrinsicReturnInvalid,
+
+		/// A balance calculation would overflow or underflow.
+		BalanceOverflow,
+
+		/// Only the dot mog founder is allowed to do this.
+		FounderOnly,
+
+		/// Maximum number of adaptations reached.
+		MaxAdaptationsReached,
+
+		/// The adaptation id (hash) already exists.
+		AdaptationAlreadyExists,
+
+		/// The game is currently deactivated.
+		GameNotActivated,
+
+		/// The game feature is currently disabled.
+		FeatureDisabled,
+
+		/// The price table is empty, too long or contains invalid prices.
+		PriceTableInvalid,
+
+		/// Account naming hasn't been unlocked for this account.
+		AccountNamingLocked,
+
+		/// The name is shorter than the minimum length.
+		NameTooShort,
+
+		/// The name is longer than the maximum length.
+		NameTooLong,
+
+		/// The name isn't valid UTF-8.
+		NameInvalid,
+
+		/// The name is already taken.
+		NameAlreadyTaken,
+
+		/// There is no name registered.
+		NameNotFound,
 	}
-}
 
-// Dispatchable functions allows users to interact with the pallet and invoke state changes.
-// These functions materialize as "extrinsics", which are often compared to transactions.
-// Dispatchable functions must be annotated with a weight and must return a DispatchResult.
-decl_module! {
-	pub struct Module<T: Config> for enum Call where origin: T::Origin {
-		// Errors must be initialized if they are used by the pallet.
-		type Error = Error<T>;
-
-		// Events must be initialized if they are used by the pallet.
-		fn deposit_event() = default;
-
-		/// An example dispatchable that takes a singles value as a parameter, writes the value to
-		/// storage and emits an event. This function must be dispatched by a signed extrinsic.
-		#[weight = 10_000 + T::DbWeight::get().writes(1)]
-		fn do_something(origin, something: u32) -> dispatch::DispatchResult {
-			// Check that the extrinsic was signed and get the signer.
-			// This function will return an error if the extrinsic is not signed.
-			// https://substrate.dev/docs/en/knowledgebase/runtime/origin
-			let who = ensure_signed(origin)?;
-
-			// Update storage.
-			Something::put(something);
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
 
-			// Emit an event.
-			Self::deposit_event(RawEvent::SomethingStored(something, who));
-			// Return a successful DispatchResult
-			Ok(())
-		}
-
-		/// An example dispatchable that may throw a custom error.
-		#[weight = 10_000 + T::DbWeight::get().reads_writes(1,1)]
-		fn cause_error(origin) -> dispatch::DispatchResult {
-			let _who = ensure_signed(origin)?;
-
-			// Read a value from storage.
-			match Something::get() {
-				// Return an error if the value has not been set.
-				None => Err(Error::<T>::NoneValue)?,
-				Some(old) => {
-					// Increment the value read from storage; will error in the event of overflow.
-					let new = old.checked_add(1).ok_or(Error::<T>::StorageOverflow)?;
-					// Update the value in storage with the incremented result.
-					Something::put(new);
-					Ok(())
-				},
-			}
+		/// Reserve the weight of the auctions and game events finalized in this block.
+		/// The sweep of stale entries only uses the space left at the end of the block, it registers its weight itself.
+		fn on_initialize(block_number: T::BlockNumber) -> Weight {
+
+			let auctions = Self::auctions_expire_at(block_number);
+			let bids: usize = auctions.iter().map(|auction| Self::bid_accounts(auction.mogwai_id).len()).sum();
+			let game_events = Self::game_events_at_block(block_number);
+
+			T::WeightInfo::on_finalize_auctions(auctions.len() as u32, bids as u32)
+				.saturating_add(T::WeightInfo::on_finalize_events(game_events.len() as u32))
+				.saturating_add(T::DbWeight::get().reads(4 + auctions.len() as u64))
 		}
 
-		#[weight = 10_000 + T::DbWeight::get().writes(1)]
-		fn update_config(origin, index: u8, value_opt: Option<u8>) -> dispatch::DispatchResult {
+		/// Migrate the storage to the latest layout.
+		fn on_runtime_upgrade() -> Weight {
+			migration::migrate::<T>(migration::DECL_STORAGE_PREFIX)
+		}
 
-			let sender = ensure_signed(origin)?;
+		/// Check the storage invariants once the upgrade is done.
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			Self::do_try_state()
+		}
 
-			ensure!(index < GameConfig::PARAM_COUNT, Error::<T>::ConfigIndexOutOfRange);
+		/// On finalize
+		fn on_finalize(block_number: T::BlockNumber) {
+
+			let auctions = Self::auctions_expire_at(block_number);
+			let game_events = Self::game_events_at_block(block_number);
 
-			let config_opt = <AccountConfig<T>>::get(&sender);
-			let mut game_config = GameConfig::new();
-			if config_opt.is_some() {
-				game_config.parameters = config_opt.unwrap();
+			if auctions.is_empty() && game_events.is_empty() {
+				Self::sweep_idle(block_number);
+				return;
 			}
 
-			// TODO: add rules (min, max) for different configurations
-			let update_value:u8 = GameConfig::verify_update(index, game_config.parameters[usize::from(index)], value_opt);
+			Self::finalize_auctions(block_number, auctions);
+			Self::finalize_events(block_number, game_events);
+		}
+	}
+
+	// Dispatchable functions allows users to interact with the pallet and invoke state changes.
+	// These functions materialize as "extrinsics", which are often compared to transactions.
+	// Dispatchable functions must be annotated with a weight and must return a DispatchResult.
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
 
-			ensure!(update_value > 0, Error::<T>::ConfigUpdateInvalid);
+		/// Upgrade a configuration of the account to its next level.
+		#[pallet::weight(T::WeightInfo::update_config())]
+		pub fn update_config(origin: OriginFor<T>, config_type: GameConfigType, value_opt: Option<u8>) -> DispatchResultWithPostInfo {
 
-			let price = Pricing::config_update_price(index, update_value);
-			if price > 0 {
-				Self::pay_founder(sender.clone(), price.saturated_into())?;
+			let sender = ensure_signed(origin)?;
+
+			ensure!(Self::is_feature_enabled(GameFeature::ConfigUpgrades), Error::<T>::FeatureDisabled);
+
+			let mut game_config = Self::account_config(&sender).unwrap_or_default();
+
+			let update_value = game_config.verify_update(config_type, value_opt).ok_or(Error::<T>::ConfigUpdateInvalid)?;
+
+			let price = Self::config_update_price(config_type, update_value);
+			if !price.is_zero() {
+				Self::pay_founder(sender.clone(), price)?;
 			}
-			
-			//if value_opt.is_some() {
-			//	game_config.parameters[usize::from(index)] = value_opt.unwrap();
-			//} else {
-			//	let config_value = game_config.parameters[usize::from(index)].checked_add(1)
-			//		.ok_or("Overflow adding a one to index")?;
-			//		game_config.parameters[usize::from(index)] = config_value;
-			//}
 
-			game_config.parameters[usize::from(index)] = update_value;
+			game_config.set(config_type, update_value);
 
 			// updating to the new configuration
-			<AccountConfig<T>>::insert(&sender, &game_config.parameters);
+			<AccountConfig<T>>::insert(&sender, &game_config);
 
 			// Emit an event.
-			Self::deposit_event(RawEvent::AccountConfigChanged(sender, game_config.parameters));
+			Self::deposit_event(Event::AccountConfigChanged(sender, config_type, update_value));
 			
 			// Return a successful DispatchResult
-			Ok(())
+			Ok(().into())
+		}
+
+		/// Register a unique name for the account, reserving a deposit.
+		#[pallet::weight(T::WeightInfo::set_account_name())]
+		pub fn set_account_name(origin: OriginFor<T>, name: Vec<u8>) -> DispatchResultWithPostInfo {
+
+			let sender = ensure_signed(origin)?;
+
+			ensure!(Self::config_value(sender.clone(), GameConfigType::AccountNaming) > 0, Error::<T>::AccountNamingLocked);
+
+			Self::ensure_valid_name(&name)?;
+
+			let name_owner_opt = Self::account_of_name(&name);
+			ensure!(name_owner_opt.is_none() || name_owner_opt == Some(sender.clone()), Error::<T>::NameAlreadyTaken);
+
+			// a renaming keeps the already reserved deposit
+			let deposit = match Self::account_name(&sender) {
+				Some((old_name, deposit)) => {
+					<AccountOfName<T>>::remove(old_name);
+					deposit
+				},
+				None => {
+					let deposit = T::AccountNameDeposit::get();
+					T::Currency::reserve(&sender, deposit)?;
+					deposit
+				},
+			};
+
+			<AccountName<T>>::insert(&sender, (name.clone(), deposit));
+			<AccountOfName<T>>::insert(&name, &sender);
+
+			Self::deposit_event(Event::AccountNameSet(sender, name));
+
+			Ok(().into())
+		}
+
+		/// Clear the name of the account, releasing the deposit.
+		#[pallet::weight(T::WeightInfo::clear_account_name())]
+		pub fn clear_account_name(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+
+			let sender = ensure_signed(origin)?;
+
+			Self::clear_name_of_account(sender)?;
+
+			Ok(().into())
+		}
+
+		/// Clear an offensive account name, releasing the deposit.
+		#[pallet::weight(T::WeightInfo::force_clear_account_name())]
+		pub fn force_clear_account_name(origin: OriginFor<T>, who: T::AccountId) -> DispatchResultWithPostInfo {
+
+			T::ModeratorOrigin::ensure_origin(origin)?;
+
+			Self::clear_name_of_account(who)?;
+
+			Ok(().into())
+		}
+
+		/// Name a mogwai, reserving a deposit.
+		#[pallet::weight(T::WeightInfo::name_mogwai())]
+		pub fn name_mogwai(origin: OriginFor<T>, mogwai_id: T::Hash, name: Vec<u8>) -> DispatchResultWithPostInfo {
+
+			let sender = ensure_signed(origin)?;
+
+			let owner = Self::owner_of(mogwai_id).ok_or(Error::<T>::MogwaiDoesntExists)?;
+			ensure!(owner == sender, Error::<T>::MogwaiNotOwned);
+
+			Self::ensure_unlocked(mogwai_id)?;
+			Self::ensure_valid_name(&name)?;
+
+			let unique = T::UniqueMogwaiNames::get();
+			if unique {
+				let named_mogwai_opt = Self::mogwai_of_name(&name);
+				ensure!(named_mogwai_opt.is_none() || named_mogwai_opt == Some(mogwai_id), Error::<T>::NameAlreadyTaken);
+			}
+
+			// a renaming keeps the already reserved deposit
+			let deposit = match Self::mogwai_name(mogwai_id) {
+				Some((old_name, deposit)) => {
+					<MogwaiOfName<T>>::remove(old_name);
+					deposit
+				},
+				None => {
+					let deposit = T::MogwaiNameDeposit::get();
+					T::Currency::reserve(&sender, deposit)?;
+					deposit
+				},
+			};
+
+			<MogwaiName<T>>::insert(mogwai_id, (name.clone(), deposit));
+			if unique {
+				<MogwaiOfName<T>>::insert(&name, mogwai_id);
+			}
+
+			Self::deposit_event(Event::MogwaiNamed(sender, mogwai_id, name));
+
+			Ok(().into())
 		}
 
 		/// Set price of mogwai.
-		#[weight = 10_000 + T::DbWeight::get().writes(1)]
-        fn set_price(origin, mogwai_id: T::Hash, new_price: BalanceOf<T>) -> dispatch::DispatchResult {
+		#[pallet::weight(T::WeightInfo::set_price())]
+        pub fn set_price(origin: OriginFor<T>, mogwai_id: T::Hash, new_price: BalanceOf<T>) -> DispatchResultWithPostInfo {
 
             let sender = ensure_signed(origin)?;
 
+            ensure!(Self::game_activated(), Error::<T>::GameNotActivated);
+            ensure!(Self::is_feature_enabled(GameFeature::DirectSales), Error::<T>::FeatureDisabled);
+
             ensure!(Mogwais::<T>::contains_key(mogwai_id), Error::<T>::MogwaiDoesntExists);
 
 			let owner = Self::owner_of(mogwai_id).ok_or("No owner for this mogwai")?;
 			
 			ensure!(owner == sender, "You don't own this mogwai");
 
+			ensure!(!Self::is_stashed(mogwai_id), Error::<T>::MogwaiStashed);
+
+			// a listed mogwai can change its price, a price of zero withdraws the listing
+			let lock = Self::lock_of(mogwai_id);
+			ensure!(lock.is_none() || lock == Some(MogwaiLock::Listed), Error::<T>::MogwaiLocked);
+
             let mut mogwai = Self::mogwai(mogwai_id);
             mogwai.price = new_price;
 
             <Mogwais<T>>::insert(mogwai_id, mogwai);
 
-            Self::deposit_event(RawEvent::PriceSet(sender, mogwai_id, new_price));
+			if new_price.is_zero() {
+				<MogwaiLocks<T>>::remove(mogwai_id);
+			} else {
+				<MogwaiLocks<T>>::insert(mogwai_id, MogwaiLock::Listed);
+			}
+
+            Self::deposit_event(Event::PriceSet(sender, mogwai_id, new_price));
             
-            Ok(())
+            Ok(().into())
         }
 
 		/// Create a new mogwai.
-		#[weight = 10_000 + T::DbWeight::get().writes(1)]
-		fn create_mogwai(origin) -> dispatch::DispatchResult {
+		#[pallet::weight(T::WeightInfo::create_mogwai())]
+		pub fn create_mogwai(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
 			
 			let sender = ensure_signed(origin)?;
 
 			// ensure that we have enough space
-			ensure!(Self::ensure_not_max_mogwais(sender.clone()), Error::<T>::MaxMogwaisInAccount);
+			ensure!(Self::has_space(sender.clone()), Error::<T>::MaxMogwaisInAccount);
 
 			//let data_hash = T::Hashing::hash(random_bytes.as_bytes());
 			let block_number = <frame_system::Pallet<T>>::block_number();
@@ -438,82 +1024,175 @@ decl_module! {
 
 			Self::mint(sender, random_hash, new_mogwai, None)?;
 			
-			Ok(())
+			Ok(().into())
 		}
 		
-		/// Remove an old mogwai.
-		#[weight = 10_000 + T::DbWeight::get().writes(1)]
-		fn remove_mogwai(origin, mogwai_id: T::Hash) -> dispatch::DispatchResult {
+		/// Mint a specific mogwai into an account, for promotional events.
+		/// Only gen 0 mogwais can be minted without a bios.
+		#[pallet::weight(T::WeightInfo::mint_to())]
+		pub fn mint_to(origin: OriginFor<T>, to: T::AccountId, dna: T::Hash, gen: u32, rarity: RarityType, with_bios: bool) -> DispatchResultWithPostInfo {
 
-            let sender = ensure_signed(origin)?;
+			T::AdminOrigin::ensure_origin(origin)?;
 
-			ensure!(sender == Self::key(), "only the dot mog founder can remove mogwais, without sacrificing them.");
+			ensure!(gen <= MAX_GENERATION, Error::<T>::GenerationInvalid);
+			ensure!(with_bios || gen == 0, Error::<T>::MintWithoutBios);
 
-			let owner = Self::owner_of(mogwai_id).ok_or("No owner for this mogwai")?;
-			
-			ensure!(owner == sender, "You don't own this mogwai");
+			// ensure that we have enough space
+			ensure!(Self::has_space(to.clone()), Error::<T>::MaxMogwaisInAccount);
+
+			Self::mint_specific(to, dna, gen, rarity, with_bios)?;
+
+			Ok(().into())
+		}
+
+		/// Mint a batch of specific mogwais into accounts, all accounts need enough space.
+		#[pallet::weight(T::WeightInfo::mint_to_batch(mogwais.len() as u32))]
+		pub fn mint_to_batch(origin: OriginFor<T>, mogwais: Vec<(T::AccountId, T::Hash, u32, RarityType)>, with_bios: bool) -> DispatchResultWithPostInfo {
+
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			ensure!(mogwais.len() <= MAX_MINT_BATCH, Error::<T>::MaxMintBatchSize);
+
+			// check everything first, as a failing mint would leave the batch half done
+			let mut needed_space: Vec<(T::AccountId, u64)> = Vec::new();
+			for (to, _, gen, _) in mogwais.iter() {
+				ensure!(*gen <= MAX_GENERATION, Error::<T>::GenerationInvalid);
+				ensure!(with_bios || *gen == 0, Error::<T>::MintWithoutBios);
+				match needed_space.iter_mut().find(|(account, _)| account == to) {
+					Some((_, needed)) => *needed += 1,
+					None => needed_space.push((to.clone(), 1)),
+				}
+			}
+			for (to, needed) in needed_space.iter() {
+				ensure!(Self::free_space(to.clone()) >= *needed, Error::<T>::MaxMogwaisInAccount);
+			}
+			Self::all_mogwais_count().checked_add(mogwais.len() as u64)
+				.ok_or("Overflow adding new mogwais to total supply")?;
+
+			for (to, dna, gen, rarity) in mogwais {
+				Self::mint_specific(to, dna, gen, rarity, with_bios)?;
+			}
+
+			Ok(().into())
+		}
+
+		/// Remove an old mogwai, whatever it is locked for, its auction and game events are canceled.
+		/// Only the founder key can remove its own mogwais this way, an `AdminOrigin` has no account owning mogwais.
+		#[pallet::weight(T::WeightInfo::remove_mogwai())]
+		pub fn remove_mogwai(origin: OriginFor<T>, mogwai_id: T::Hash) -> DispatchResultWithPostInfo {
+
+            let sender = EnsureFounder::<T>::ensure_origin(origin)?;
+
+			let owner = Self::owner_of(mogwai_id).ok_or(Error::<T>::MogwaiDoesntExists)?;
+			ensure!(owner == sender, Error::<T>::MogwaiNotOwned);
 
 			Self::remove(sender, mogwai_id)?;
 
-            Ok(())
+            Ok(().into())
 		}
 
 		/// Transfer mogwai to a new account.
-		#[weight = 10_000 + T::DbWeight::get().writes(1)]
-		fn transfer(origin, to: T::AccountId, mogwai_id: T::Hash) -> dispatch::DispatchResult {
+		/// Only the founder key can give away its own mogwais this way, an `AdminOrigin` has no account owning mogwais.
+		#[pallet::weight(T::WeightInfo::transfer())]
+		pub fn transfer(origin: OriginFor<T>, to: T::AccountId, mogwai_id: T::Hash) -> DispatchResultWithPostInfo {
 
-            let sender = ensure_signed(origin)?;
-
-			ensure!(sender == Self::key(), "only the dot mog founder can transfer mogwais, without paying for them.");
+            let sender = EnsureFounder::<T>::ensure_origin(origin)?;
 
 			let owner = Self::owner_of(mogwai_id).ok_or(Error::<T>::MogwaiDoesntExists)?;
 			ensure!(owner == sender, Error::<T>::MogwaiNotOwned);
 
 			// ensure that we have enough space
-			ensure!(Self::ensure_not_max_mogwais(to.clone()), Error::<T>::MaxMogwaisInAccount);
+			ensure!(Self::has_space(to.clone()), Error::<T>::MaxMogwaisInAccount);
 
             Self::transfer_from(sender, to, mogwai_id)?;
 
-            Ok(())
+            Ok(().into())
 		}
 		
+		/// Move a mogwai into the stash, to make space in the account.
+		#[pallet::weight(T::WeightInfo::stash_mogwai())]
+		pub fn stash_mogwai(origin: OriginFor<T>, mogwai_id: T::Hash) -> DispatchResultWithPostInfo {
+
+			let sender = ensure_signed(origin)?;
+
+			let owner = Self::owner_of(mogwai_id).ok_or(Error::<T>::MogwaiDoesntExists)?;
+			ensure!(owner == sender, Error::<T>::MogwaiNotOwned);
+
+			ensure!(!Self::is_stashed(mogwai_id), Error::<T>::MogwaiStashed);
+			Self::ensure_unlocked(mogwai_id)?;
+			ensure!(Self::ensure_not_max_stash(sender.clone()), Error::<T>::MaxStashSize);
+
+			Self::remove_owned(&sender, mogwai_id)?;
+			Self::insert_owned(&sender, mogwai_id, true)?;
+
+			Self::deposit_event(Event::MogwaiStashed(sender, mogwai_id));
+
+			Ok(().into())
+		}
+
+		/// Move a mogwai back from the stash into the account.
+		#[pallet::weight(T::WeightInfo::unstash_mogwai())]
+		pub fn unstash_mogwai(origin: OriginFor<T>, mogwai_id: T::Hash) -> DispatchResultWithPostInfo {
+
+			let sender = ensure_signed(origin)?;
+
+			let owner = Self::owner_of(mogwai_id).ok_or(Error::<T>::MogwaiDoesntExists)?;
+			ensure!(owner == sender, Error::<T>::MogwaiNotOwned);
+
+			ensure!(Self::is_stashed(mogwai_id), Error::<T>::MogwaiNotStashed);
+			ensure!(Self::ensure_not_max_mogwais(sender.clone()), Error::<T>::MaxMogwaisInAccount);
+
+			Self::remove_owned(&sender, mogwai_id)?;
+			Self::insert_owned(&sender, mogwai_id, false)?;
+
+			Self::deposit_event(Event::MogwaiUnstashed(sender, mogwai_id));
+
+			Ok(().into())
+		}
+
 		/// Sacrifice mogwai to an other mogwai.
-		#[weight = 10_000 + T::DbWeight::get().writes(1)]
-		fn sacrifice(origin, mogwai_id_1: T::Hash) -> dispatch::DispatchResult {
+		#[pallet::weight(T::WeightInfo::sacrifice())]
+		pub fn sacrifice(origin: OriginFor<T>, mogwai_id_1: T::Hash) -> DispatchResultWithPostInfo {
 
             let sender = ensure_signed(origin)?;
 
+            ensure!(Self::is_feature_enabled(GameFeature::Sacrifice), Error::<T>::FeatureDisabled);
+
 			let owner = Self::owner_of(mogwai_id_1).ok_or(Error::<T>::MogwaiDoesntExists)?;
 			ensure!(owner == sender, Error::<T>::MogwaiNotOwned);
+
+			Self::ensure_unlocked(mogwai_id_1)?;
 	
 			// make sure that there is no pending game event on the mogwai before sacrificing it.
-			if GameEventsOfMogwai::<T>::contains_key(&mogwai_id_1) {
-				let open_game_events = Self::game_events_of_mogwai(&mogwai_id_1);
-				ensure!(open_game_events.is_empty(), Error::<T>::MogwaiHasGameEvents);
-			}
+			Self::ensure_no_game_events(mogwai_id_1)?;
 
 			// TODO this needs to be check, reworked and corrected, add dynasty feature !!!
 			let mogwai_1 = Self::mogwai(mogwai_id_1);
 			if mogwai_1.gen == 0 {
-				Self::pay_fee(sender.clone(), Pricing::fee_price(FeeType::Remove).saturated_into())?;
+				Self::pay_fee(sender.clone(), Self::price(PriceType::Fee, FeeType::Remove as usize))?;
 				Self::remove(sender, mogwai_id_1)?;
 			} else {
 				ensure!(MogwaisBios::<T>::contains_key(mogwai_id_1), Error::<T>::MogwaiHasNoBios);
 				let mogwai_bios_1 = Self::mogwai_bios(mogwai_id_1);
-				let intrinsic = mogwai_bios_1.intrinsic / Pricing::intrinsic_return(mogwai_bios_1.phases.len()).saturated_into();
+				let intrinsic_return = Self::price(PriceType::IntrinsicReturn, mogwai_bios_1.phases.len());
+				// only the quotient is returned, the rest of the intrinsic value, remainder included, is burned
+				let intrinsic = mogwai_bios_1.intrinsic.checked_div(&intrinsic_return).ok_or(Error::<T>::IntrinsicReturnInvalid)?;
 				Self::remove(sender.clone(), mogwai_id_1)?;
-				let _ = T::Currency::deposit_into_existing(&sender, intrinsic)?;
+				// the payout can't fail, so the mogwai isn't lost without its intrinsic value
+				let _ = T::Currency::deposit_creating(&sender, intrinsic);
 			}
 
-            Ok(())
+            Ok(().into())
 		}
 
 		/// Sacrifice mogwai to an other mogwai.
-		#[weight = 10_000 + T::DbWeight::get().writes(1)]
-		fn sacrifice_into(origin, mogwai_id_1: T::Hash, mogwai_id_2: T::Hash) -> dispatch::DispatchResult {
+		#[pallet::weight(T::WeightInfo::sacrifice_into())]
+		pub fn sacrifice_into(origin: OriginFor<T>, mogwai_id_1: T::Hash, mogwai_id_2: T::Hash) -> DispatchResultWithPostInfo {
 
             let sender = ensure_signed(origin)?;
 
+            ensure!(Self::is_feature_enabled(GameFeature::Sacrifice), Error::<T>::FeatureDisabled);
+
 			let owner1 = Self::owner_of(mogwai_id_1).ok_or(Error::<T>::MogwaiDoesntExists)?;
 			let owner2 = Self::owner_of(mogwai_id_2).ok_or(Error::<T>::MogwaiDoesntExists)?;
 			ensure!(owner1 == owner2, Error::<T>::MogwaiNotOwned);
@@ -522,16 +1201,13 @@ decl_module! {
 			// asacrificing into the same mogwai isn't allowed
 			ensure!(mogwai_id_1 != mogwai_id_2, Error::<T>::MogwaiSame);
 
+			Self::ensure_unlocked(mogwai_id_1)?;
+			Self::ensure_unlocked(mogwai_id_2)?;
+
 			// make sure that there is no pending game event on the mogwai before sacrificing it.
-			if GameEventsOfMogwai::<T>::contains_key(&mogwai_id_1) {
-				let open_game_events = Self::game_events_of_mogwai(&mogwai_id_1);
-				ensure!(open_game_events.is_empty(), Error::<T>::MogwaiHasGameEvents);
-			}
+			Self::ensure_no_game_events(mogwai_id_1)?;
 
-			if GameEventsOfMogwai::<T>::contains_key(&mogwai_id_2) {
-				let open_game_events = Self::game_events_of_mogwai(&mogwai_id_2);
-				ensure!(open_game_events.is_empty(), Error::<T>::MogwaiHasGameEvents);
-			}
+			Self::ensure_no_game_events(mogwai_id_2)?;
 
 			// TODO this needs to be check, reworked and corrected, add dynasty feature !!!
 			let mogwai_1 = Self::mogwai(mogwai_id_1);
@@ -545,77 +1221,253 @@ decl_module! {
 			let mogwai_bios_1 = Self::mogwai_bios(mogwai_id_1);
 			let mut mogwai_bios_2 = Self::mogwai_bios(mogwai_id_2);
 
+			// calculate the new intrinsic value before touching the storage
+			let new_intrinsic = mogwai_bios_2.intrinsic.checked_add(&mogwai_bios_1.intrinsic).ok_or(Error::<T>::IntrinsicOverflow)?;
+
 			let gen_jump = Breeding::sacrifice(mogwai_1.gen, mogwai_1.rarity as u32, mogwai_bios_1.metaxy.clone(), mogwai_2.gen, mogwai_2.rarity as u32, mogwai_bios_2.metaxy.clone());
-			if gen_jump > 0 && (mogwai_2.gen + gen_jump) <= 16 {
+			if gen_jump > 0 && mogwai_2.gen.saturating_add(gen_jump) <= 16 {
 				mogwai_2.gen += gen_jump;
 				<Mogwais<T>>::insert(mogwai_id_2, mogwai_2);
 			}
 
-			if mogwai_bios_1.intrinsic > Zero::zero() {
-				mogwai_bios_2.intrinsic += mogwai_bios_1.intrinsic; // TODO check overflow
-				<MogwaisBios<T>>::insert(mogwai_id_2, mogwai_bios_2);
-			}
+			mogwai_bios_2.intrinsic = new_intrinsic;
+			Self::unlock_adaptations(&mut mogwai_bios_2, AdaptationTrigger::Sacrifice, mogwai_1.rarity as u32);
+			<MogwaisBios<T>>::insert(mogwai_id_2, mogwai_bios_2);
 
 			Self::remove(sender.clone(), mogwai_id_1)?;
 
-            Ok(())
+            Ok(().into())
 		}
 
-		/// Buy a mogwai.
-		#[weight = 10_000 + T::DbWeight::get().writes(1)]
-		fn buy_mogwai(origin, mogwai_id: T::Hash, max_price: BalanceOf<T>) -> dispatch::DispatchResult {
-			
-			let sender = ensure_signed(origin)?;
+		/// Rotate the dot mog founder key.
+		#[pallet::weight(T::WeightInfo::set_key())]
+		pub fn set_key(origin: OriginFor<T>, new_key: T::AccountId) -> DispatchResultWithPostInfo {
 
-			ensure!(Mogwais::<T>::contains_key(mogwai_id), Error::<T>::MogwaiDoesntExists);
+			Self::ensure_founder_or_root(origin)?;
 
-			let owner = Self::owner_of(mogwai_id).ok_or(Error::<T>::MogwaiDoesntExists)?;
-			ensure!(owner != sender, "You already own this mogwai");
-			
-			let mut mogwai = Self::mogwai(mogwai_id);
-		
-			let mogwai_price = mogwai.price;
+			<Key<T>>::put(&new_key);
 
-			ensure!(!mogwai_price.is_zero(), "You can't buy this mogwai, there is no price");
-		
-			ensure!(mogwai_price <= max_price, "You can't buy this mogwai, price exceeds your max price limit");
+			Self::deposit_event(Event::KeyChanged(new_key));
 
-			// ensure that we have enough space
-			ensure!(Self::ensure_not_max_mogwais(sender.clone()), Error::<T>::MaxMogwaisInAccount);
+			Ok(().into())
+		}
 
-			T::Currency::transfer(&sender, &owner, mogwai_price, ExistenceRequirement::KeepAlive)?;
+		/// Set the account receiving the game payments, `None` pays to the founder.
+		#[pallet::weight(T::WeightInfo::set_treasury())]
+		pub fn set_treasury(origin: OriginFor<T>, treasury: Option<T::AccountId>) -> DispatchResultWithPostInfo {
 
-			// Transfer the mogwai using `transfer_from()` including a proof of why it cannot fail
-			Self::transfer_from(owner.clone(), sender.clone(), mogwai_id)
-				.expect("`owner` is shown to own the mogwai; \
-				`owner` must have greater than 0 mogwai, so transfer cannot cause underflow; \
-				`all_mogwai_count` shares the same type as `owned_mogwai_count` \
-				and minting ensure there won't ever be more than `max()` mogwais, \
-				which means transfer cannot cause an overflow; \
-				qed");
-			
-			// Reset mogwai price back to zero, and update the storage
-			mogwai.price = Zero::zero();
+			T::AdminOrigin::ensure_origin(origin)?;
 
-			<Mogwais<T>>::insert(mogwai_id, mogwai);
+			match &treasury {
+				Some(account) => <Treasury<T>>::put(account),
+				None => <Treasury<T>>::kill(),
+			}
 
-			Self::deposit_event(RawEvent::Bought(sender, owner, mogwai_id, mogwai_price));
-			
-			Ok(())
+			Self::deposit_event(Event::TreasuryChanged(treasury));
+
+			Ok(().into())
 		}
 
-		/// Morph a gen 0 mogwai
-		#[weight = 10_000 + T::DbWeight::get().writes(1)]
-		fn morph_mogwai(origin, mogwai_id: T::Hash) -> dispatch::DispatchResult {
+		/// Activate or deactivate the game, pending auctions are still finalized.
+		#[pallet::weight(T::WeightInfo::set_game_activated())]
+		pub fn set_game_activated(origin: OriginFor<T>, activated: bool) -> DispatchResultWithPostInfo {
 
-            let sender = ensure_signed(origin)?;
+			T::AdminOrigin::ensure_origin(origin)?;
 
-            ensure!(Mogwais::<T>::contains_key(mogwai_id), Error::<T>::MogwaiDoesntExists);
+			GameActivated::<T>::put(activated);
 
-			let owner = Self::owner_of(mogwai_id).ok_or("No owner for this mogwai")?;		
-			ensure!(owner == sender, "You don't own this mogwai");
+			Self::deposit_event(Event::GameActivationChanged(activated));
 
-			let mut mogwai = Self::mogwai(mogwai_id);
+			Ok(().into())
+		}
+
+		/// Change a price table of the game economy.
+		#[pallet::weight(T::WeightInfo::set_pricing())]
+		pub fn set_pricing(origin: OriginFor<T>, price_type: PriceType, table: Vec<BalanceOf<T>>) -> DispatchResultWithPostInfo {
+
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			ensure!(Pricing::is_valid_table(price_type, &table), Error::<T>::PriceTableInvalid);
+
+			<PriceTables<T>>::insert(price_type, &table);
+
+			Self::deposit_event(Event::PricingChanged(price_type, table));
+
+			Ok(().into())
+		}
+
+		/// Enable or disable a single game feature.
+		#[pallet::weight(T::WeightInfo::set_feature_status())]
+		pub fn set_feature_status(origin: OriginFor<T>, feature: GameFeature, enabled: bool) -> DispatchResultWithPostInfo {
+
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			DisabledFeatures::<T>::mutate(|disabled_features| {
+				if enabled {
+					*disabled_features &= !feature.flag();
+				} else {
+					*disabled_features |= feature.flag();
+				}
+			});
+
+			Self::deposit_event(Event::FeatureStatusChanged(feature, enabled));
+
+			Ok(().into())
+		}
+
+		/// Change the way the randomness of a breeding is drawn, pending breedings keep their mode.
+		#[pallet::weight(T::WeightInfo::set_randomness_mode())]
+		pub fn set_randomness_mode(origin: OriginFor<T>, mode: RandomnessMode) -> DispatchResultWithPostInfo {
+
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			BreedingRandomness::<T>::put(mode);
+
+			Self::deposit_event(Event::RandomnessModeChanged(mode));
+
+			Ok(().into())
+		}
+
+		/// Enable or disable the legacy hatching, which segments mogwais with the hash of their genesis block.
+		#[pallet::weight(T::WeightInfo::set_legacy_hatching())]
+		pub fn set_legacy_hatching(origin: OriginFor<T>, enabled: bool) -> DispatchResultWithPostInfo {
+
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			LegacyHatching::<T>::put(enabled);
+
+			Self::deposit_event(Event::LegacyHatchingChanged(enabled));
+
+			Ok(().into())
+		}
+
+		/// Add a new adaptation, that can be earned by mogwais.
+		#[pallet::weight(T::WeightInfo::add_adaptation())]
+		pub fn add_adaptation(origin: OriginFor<T>, trigger: AdaptationTrigger, requirement: u32, effect: AdaptationEffect) -> DispatchResultWithPostInfo {
+
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			let mut adaptation_ids = Self::adaptation_ids();
+			ensure!(adaptation_ids.len() < MAX_ADAPTATIONS, Error::<T>::MaxAdaptationsReached);
+
+			let adaptation_id = Self::generate_random_hash(b"adaptation", Self::key());
+			ensure!(!Adaptations::<T>::contains_key(adaptation_id), Error::<T>::AdaptationAlreadyExists);
+
+			let adaptation = Adaptation {
+				id: adaptation_id,
+				trigger,
+				requirement,
+				effect,
+			};
+
+			<Adaptations<T>>::insert(adaptation_id, adaptation);
+			adaptation_ids.push(adaptation_id);
+			<AdaptationIds<T>>::put(adaptation_ids);
+
+			Self::deposit_event(Event::AdaptationAdded(adaptation_id));
+
+			Ok(().into())
+		}
+
+		/// Feed a mogwai, to increase its intrinsic value.
+		///
+		/// Any account can feed any mogwai, feeding is a tip that only ever adds to the value the owner holds.
+		#[pallet::weight(T::WeightInfo::feed_mogwai())]
+		pub fn feed_mogwai(origin: OriginFor<T>, mogwai_id: T::Hash, amount: BalanceOf<T>) -> DispatchResultWithPostInfo {
+
+			let sender = ensure_signed(origin)?;
+
+			ensure!(Self::game_activated(), Error::<T>::GameNotActivated);
+
+			ensure!(Mogwais::<T>::contains_key(mogwai_id), Error::<T>::MogwaiDoesntExists);
+			ensure!(MogwaisBios::<T>::contains_key(mogwai_id), Error::<T>::MogwaiHasNoBios);
+			Self::ensure_unlocked(mogwai_id)?;
+
+			ensure!(amount >= T::MinFeedAmount::get(), Error::<T>::FeedAmountTooLow);
+
+			let mogwai_bios = Self::mogwai_bios(mogwai_id);
+
+			// each level of the mogwai allows to hold more intrinsic value
+			let max_intrinsic = T::MaxIntrinsicPerLevel::get().saturating_mul((mogwai_bios.level as u32).into());
+			let new_intrinsic = mogwai_bios.intrinsic.checked_add(&amount).ok_or(Error::<T>::IntrinsicOverflow)?;
+			ensure!(new_intrinsic <= max_intrinsic, Error::<T>::MaxIntrinsicReached);
+
+			Self::tip_mogwai(sender.clone(), amount, mogwai_id, mogwai_bios)?;
+
+			Self::deposit_event(Event::MogwaiFed(sender, mogwai_id, amount));
+
+			Ok(().into())
+		}
+
+		/// Buy a mogwai.
+		#[pallet::weight(T::WeightInfo::buy_mogwai())]
+		pub fn buy_mogwai(origin: OriginFor<T>, mogwai_id: T::Hash, max_price: BalanceOf<T>) -> DispatchResultWithPostInfo {
+			
+			let sender = ensure_signed(origin)?;
+
+			ensure!(Self::game_activated(), Error::<T>::GameNotActivated);
+			ensure!(Self::is_feature_enabled(GameFeature::DirectSales), Error::<T>::FeatureDisabled);
+
+			ensure!(Mogwais::<T>::contains_key(mogwai_id), Error::<T>::MogwaiDoesntExists);
+
+			let owner = Self::owner_of(mogwai_id).ok_or(Error::<T>::MogwaiDoesntExists)?;
+			ensure!(owner != sender, "You already own this mogwai");
+			ensure!(!Self::is_stashed(mogwai_id), Error::<T>::MogwaiStashed);
+			
+			let mut mogwai = Self::mogwai(mogwai_id);
+		
+			let mogwai_price = mogwai.price;
+
+			ensure!(!mogwai_price.is_zero(), "You can't buy this mogwai, there is no price");
+			ensure!(Self::lock_of(mogwai_id) == Some(MogwaiLock::Listed), Error::<T>::MogwaiNotListed);
+		
+			ensure!(mogwai_price <= max_price, "You can't buy this mogwai, price exceeds your max price limit");
+
+			// ensure that we have enough space
+			ensure!(Self::has_space(sender.clone()), Error::<T>::MaxMogwaisInAccount);
+
+			T::Currency::transfer(&sender, &owner, mogwai_price, ExistenceRequirement::KeepAlive)?;
+
+			// the sale releases the listing
+			<MogwaiLocks<T>>::remove(mogwai_id);
+
+			// Transfer the mogwai using `transfer_from()` including a proof of why it cannot fail
+			Self::transfer_from(owner.clone(), sender.clone(), mogwai_id)
+				.expect("`owner` is shown to own the mogwai; \
+				`owner` must have greater than 0 mogwai, so transfer cannot cause underflow; \
+				`all_mogwai_count` shares the same type as `owned_mogwai_count` \
+				and minting ensure there won't ever be more than `max()` mogwais, \
+				which means transfer cannot cause an overflow; \
+				qed");
+			
+			// Reset mogwai price back to zero, and update the storage
+			mogwai.price = Zero::zero();
+
+			<Mogwais<T>>::insert(mogwai_id, mogwai);
+
+			Self::deposit_event(Event::Bought(sender, owner, mogwai_id, mogwai_price));
+			
+			Ok(().into())
+		}
+
+		/// Morph a gen 0 mogwai
+		#[pallet::weight(T::WeightInfo::morph_mogwai())]
+		pub fn morph_mogwai(origin: OriginFor<T>, mogwai_id: T::Hash) -> DispatchResultWithPostInfo {
+
+            let sender = ensure_signed(origin)?;
+
+            ensure!(Self::game_activated(), Error::<T>::GameNotActivated);
+            ensure!(Self::is_feature_enabled(GameFeature::Breeding), Error::<T>::FeatureDisabled);
+
+            ensure!(Mogwais::<T>::contains_key(mogwai_id), Error::<T>::MogwaiDoesntExists);
+
+			let owner = Self::owner_of(mogwai_id).ok_or("No owner for this mogwai")?;		
+			ensure!(owner == sender, "You don't own this mogwai");
+
+			ensure!(!Self::is_stashed(mogwai_id), Error::<T>::MogwaiStashed);
+			Self::ensure_unlocked(mogwai_id)?;
+
+			let mut mogwai = Self::mogwai(mogwai_id);
 			ensure!(mogwai.gen == 0, Error::<T>::MogwaiIncompatibleGeneration);
 
 			let block_number = <frame_system::Pallet<T>>::block_number();
@@ -627,7 +1479,7 @@ decl_module! {
 			dx.copy_from_slice(&mogwai.dna.as_ref()[0..16]);
 			dy.copy_from_slice(&mogwai.dna.as_ref()[16..32]);
 	
-			let final_dna : [u8;32] = Breeding::pairing(breed_type, dx, dy);	
+			let final_dna : [u8;32] = Breeding::pairing(breed_type, dx, dy, &[], &[]);	
 
 			// don't know a better way
 			for i in 0..32 {
@@ -636,15 +1488,18 @@ decl_module! {
 
             <Mogwais<T>>::insert(mogwai_id, mogwai);
 
-			Ok(())
+			Ok(().into())
 		}
 
 		/// Breed a mogwai.
-		#[weight = 10_000 + T::DbWeight::get().writes(1)]
-		fn breed_mogwai(origin, mogwai_id_1: T::Hash, mogwai_id_2: T::Hash) -> dispatch::DispatchResult {
+		#[pallet::weight(T::WeightInfo::breed_mogwai())]
+		pub fn breed_mogwai(origin: OriginFor<T>, mogwai_id_1: T::Hash, mogwai_id_2: T::Hash) -> DispatchResultWithPostInfo {
 			
 			let sender = ensure_signed(origin)?;
 
+			ensure!(Self::game_activated(), Error::<T>::GameNotActivated);
+			ensure!(Self::is_feature_enabled(GameFeature::Breeding), Error::<T>::FeatureDisabled);
+
 			ensure!(Mogwais::<T>::contains_key(mogwai_id_1), Error::<T>::MogwaiDoesntExists);
 			ensure!(Mogwais::<T>::contains_key(mogwai_id_2), Error::<T>::MogwaiDoesntExists);
 
@@ -654,8 +1509,15 @@ decl_module! {
 			// breeding into the same mogwai isn't allowed
 			ensure!(mogwai_id_1 != mogwai_id_2, Error::<T>::MogwaiSame);
 
+			// stashed mogwais can't breed
+			ensure!(!Self::is_stashed(mogwai_id_1) && !Self::is_stashed(mogwai_id_2), Error::<T>::MogwaiStashed);
+
+			// neither can mogwais that are listed, auctioned or not hatched
+			Self::ensure_unlocked(mogwai_id_1)?;
+			Self::ensure_unlocked(mogwai_id_2)?;
+
 			// ensure that we have enough space
-			ensure!(Self::ensure_not_max_mogwais(sender.clone()), Error::<T>::MaxMogwaisInAccount);
+			ensure!(Self::has_space(sender.clone()), Error::<T>::MaxMogwaisInAccount);
 
 			let parents = [Self::mogwai(mogwai_id_1) , Self::mogwai(mogwai_id_2)];
 
@@ -666,10 +1528,9 @@ decl_module! {
 			let mogwai_id = Self::generate_random_hash(b"breed_mogwai", sender.clone());
 			let event_id = Self::generate_random_hash(b"breed_event", sender.clone());
 
-			let (rarity, next_gen) = Generation::next_gen(parents[0].gen, parents[0].rarity, parents[1].gen, parents[1].rarity, mogwai_id.as_ref());
+			let effects = [Self::adaptation_effects(mogwai_id_1), Self::adaptation_effects(mogwai_id_2)];
 
-			let block_number = <frame_system::Pallet<T>>::block_number();			
-			let breed_type : BreedType = Self::calculate_breedtype(block_number);
+			let block_number = <frame_system::Pallet<T>>::block_number();
 			
 			let mut dx: [u8;16] = Default::default();
 			let mut dy: [u8;16] = Default::default();
@@ -679,48 +1540,52 @@ decl_module! {
 			} else {
 				let mogwai_bios_1 = Self::mogwai_bios(mogwai_id_1);
 				let mogwai_bios_2 = Self::mogwai_bios(mogwai_id_2);
-				dx = mogwai_bios_1.metaxy[0];
-				dy = mogwai_bios_2.metaxy[0];
+				dx = *mogwai_bios_1.metaxy.first().ok_or(Error::<T>::MogwaiHasNoBios)?;
+				dy = *mogwai_bios_2.metaxy.first().ok_or(Error::<T>::MogwaiHasNoBios)?;
 
 				// add pairing price to mogwai intrinsic value TODO
-				let pairing_price:BalanceOf<T> = Pricing::pairing(parents[0].rarity, parents[1].rarity).saturated_into();
+				let pairing_price = Pricing::pairing(&Self::price_table_or_default(PriceType::Pairing), parents[0].rarity, parents[1].rarity);
 				Self::tip_mogwai(sender.clone(), pairing_price, mogwai_id_2, mogwai_bios_2)?;
 			}
 
-			let final_dna : [u8;32] = Breeding::pairing(breed_type, dx, dy);	
-			
-			// don't know a better way, then using a clone.
-			let mut final_dna_hash = mogwai_id.clone();
-			for i in 0..32 {
-				final_dna_hash.as_mut()[i] = final_dna[i];
-			}
-
-			// TODO: still no clue how to get the Hash type filled straight
-			//let mut test_u8 : [u8;32] = [0;32];
-			//let mut test_h256 = H256::from_slice(&test_u8);
-			//let mut test_hash : T::Hash = test_h256.into();
+			let mut mogwai_struct = MogwaiStruct {
+				id: mogwai_id,
+				dna: Default::default(),
+				genesis: block_number,
+				price: Zero::zero(),
+				gen: 0,
+				rarity: RarityType::Minor,
+			};
 
-			//let mut final_dna = parents[0].dna;
-			//for (i, (dna_2_element, r)) in parents[1].dna.as_ref().iter().zip(random_hash.as_ref().iter()).enumerate() {
-			//	if r % 2 == 0 {
-			//		final_dna.as_mut()[i] = *dna_2_element;
-			//	}
-			//}
+			let pending_breed = match Self::randomness_mode() {
+				RandomnessMode::Instant => {
+					let (rarity, next_gen) = Generation::next_gen(parents[0].gen, parents[0].rarity, parents[1].gen, parents[1].rarity, mogwai_id.as_ref(), &effects[0], &effects[1]);
+					let breed_type : BreedType = Self::calculate_breedtype(block_number);
+					let final_dna : [u8;32] = Breeding::pairing(breed_type, dx, dy, &effects[0], &effects[1]);
 
-			//let block_hash = <frame_system::Pallet<T>>::block_hash(block_number);
+					mogwai_struct.dna.as_mut().copy_from_slice(&final_dna);
+					mogwai_struct.gen = next_gen;
+					mogwai_struct.rarity = rarity;
+					None
+				},
+				RandomnessMode::FutureBlock => {
+					// the dna stays empty and the lowest possible outcome is shown until the mogwai hatches
+					let range = Generation::next_gen_range(parents[0].gen, parents[0].rarity, parents[1].gen, parents[1].rarity, &effects[0], &effects[1]);
+					mogwai_struct.gen = range.min_gen;
+					mogwai_struct.rarity = range.min_rarity;
+					Some(PendingBreed {
+						parents: [mogwai_id_1, mogwai_id_2],
+						gens: [parents[0].gen, parents[1].gen],
+						rarities: [parents[0].rarity, parents[1].rarity],
+						strands: [dx, dy],
+						effects,
+					})
+				},
+			};
 
 			let mut mogwai_ids: Vec<T::Hash> = Vec::new();
 			mogwai_ids.push(mogwai_id);
 
-			let mogwai_struct = MogwaiStruct {
-				id: mogwai_id,
-				dna: final_dna_hash,
-				genesis: block_number,
-				price: Zero::zero(),
-				gen: next_gen,
-				rarity: rarity,
-			};
-
 			let game_event = GameEvent {
 				id: event_id,
 				begin: block_number + GameEventType::time_till(GameEventType::Hatch).into(),
@@ -733,22 +1598,34 @@ decl_module! {
 			// mint mogwai
 			Self::mint(sender, mogwai_id, mogwai_struct, Some(game_event))?;
 
-			Ok(())
+			<MogwaiLocks<T>>::insert(mogwai_id, MogwaiLock::Incubating);
+
+			if let Some(pending_breed) = pending_breed {
+				<PendingBreeds<T>>::insert(mogwai_id, pending_breed);
+			}
+
+			Ok(().into())
 		}
 
 		/// Create a new auction.
-		#[weight = 10_000 + T::DbWeight::get().writes(1)]
-		fn create_auction(origin, mogwai_id: T::Hash, min_bid: BalanceOf<T>, expiry: T::BlockNumber) -> dispatch::DispatchResult {
+		#[pallet::weight(T::WeightInfo::create_auction())]
+		pub fn create_auction(origin: OriginFor<T>, mogwai_id: T::Hash, min_bid: BalanceOf<T>, expiry: T::BlockNumber) -> DispatchResultWithPostInfo {
 			
 			let sender = ensure_signed(origin)?;
 
+			ensure!(Self::game_activated(), Error::<T>::GameNotActivated);
+			ensure!(Self::is_feature_enabled(GameFeature::Auctions), Error::<T>::FeatureDisabled);
+
 			ensure!(Mogwais::<T>::contains_key(mogwai_id), Error::<T>::MogwaiDoesntExists);
 
 			let owner = Self::owner_of(mogwai_id).ok_or("No owner for this mogwai")?;
             ensure!(owner == sender, "You can't set an auction for a mogwai you don't own");
 
+			ensure!(!Self::is_stashed(mogwai_id), Error::<T>::MogwaiStashed);
+			Self::ensure_unlocked(mogwai_id)?;
+
             ensure!(expiry > <frame_system::Pallet<T>>::block_number(), "The expiry has to be greater than the current block number");
-            ensure!(expiry <= <frame_system::Pallet<T>>::block_number() + Self::auction_period_limit(), "The expiry has be lower than the limit block number");
+            ensure!(expiry <= <frame_system::Pallet<T>>::block_number().saturating_add(Self::auction_period_limit()), "The expiry has be lower than the limit block number");
 
             let auctions = Self::auctions_expire_at(expiry);
             ensure!(auctions.len() < MAX_AUCTIONS_PER_BLOCK, "Maximum number of auctions is reached for the target block, try another block");
@@ -764,18 +1641,22 @@ decl_module! {
 
             <MogwaiAuction<T>>::insert(mogwai_id, &new_auction);
             <Auctions<T>>::mutate(expiry, |auctions| auctions.push(new_auction.clone()));
+            <MogwaiLocks<T>>::insert(mogwai_id, MogwaiLock::Auctioned);
 
-            Self::deposit_event(RawEvent::AuctionCreated(mogwai_id, min_bid, expiry));
+            Self::deposit_event(Event::AuctionCreated(mogwai_id, min_bid, expiry));
 
-            Ok (())
+            Ok(().into())
 		}
 		
 		/// Bid on an auction.
-		#[weight = 10_000 + T::DbWeight::get().writes(1)]
-		fn bid_auction(origin, mogwai_id: T::Hash, bid: BalanceOf<T>) -> dispatch::DispatchResult {
+		#[pallet::weight(T::WeightInfo::bid_auction())]
+		pub fn bid_auction(origin: OriginFor<T>, mogwai_id: T::Hash, bid: BalanceOf<T>) -> DispatchResultWithPostInfo {
 
             let sender = ensure_signed(origin)?;
 
+            ensure!(Self::game_activated(), Error::<T>::GameNotActivated);
+            ensure!(Self::is_feature_enabled(GameFeature::Auctions), Error::<T>::FeatureDisabled);
+
 			ensure!(Mogwais::<T>::contains_key(mogwai_id), Error::<T>::MogwaiDoesntExists);
 
 			let owner = Self::owner_of(mogwai_id).ok_or("No owner for this mogwai")?;
@@ -789,6 +1670,20 @@ decl_module! {
 
             ensure!(T::Currency::free_balance(&sender) >= bid, "You don't have enough free balance for this bid");
 
+            // every bidder is listed once, and the bidders are capped to bound the auction finalization
+            let known_bidder = <Bids<T>>::contains_key((mogwai_id, sender.clone()));
+            ensure!(known_bidder || Self::bid_accounts(mogwai_id).len() < MAX_BIDDERS_PER_AUCTION, Error::<T>::MaxBiddersReached);
+
+            if known_bidder {
+                let escrow_balance = Self::bid_of((mogwai_id, sender.clone()));
+                let bid_increase = bid.checked_sub(&escrow_balance).ok_or(Error::<T>::BalanceOverflow)?;
+                T::Currency::reserve(&sender, bid_increase)?;
+            } else {
+                T::Currency::reserve(&sender, bid)?;
+                <BidAccounts<T>>::mutate(mogwai_id, |accounts| accounts.push(sender.clone()));
+            }
+            <Bids<T>>::insert((mogwai_id, sender.clone()), bid);
+
             auction.high_bid = bid;
             auction.high_bidder = sender.clone();
 
@@ -801,35 +1696,58 @@ decl_module! {
                 }
             });
 
-            if <Bids<T>>::contains_key((mogwai_id, sender.clone())) {
-                let escrow_balance = Self::bid_of((mogwai_id, sender.clone()));
-                T::Currency::reserve(&sender, bid - escrow_balance)?;
-            } else {
-                T::Currency::reserve(&sender, bid)?;
-            }
-            <Bids<T>>::insert((mogwai_id, sender.clone()), bid);
-            <BidAccounts<T>>::mutate(mogwai_id, |accounts| accounts.push(sender.clone()));
-
-            Self::deposit_event(RawEvent::Bid(mogwai_id, auction.high_bid, auction.high_bidder));
+            Self::deposit_event(Event::Bid(mogwai_id, auction.high_bid, auction.high_bidder));
 
-            Ok (())
+            Ok(().into())
 		}
-		
-		/// On finalize
-		fn on_finalize() {
 
-			let block_number = <frame_system::Pallet<T>>::block_number();
+		/// Reap the auctions, bids and game events left behind for the given mogwais, rewarding each stale entry.
+		#[pallet::weight(T::WeightInfo::reap_stale(mogwai_ids.len() as u32))]
+		pub fn reap_stale(origin: OriginFor<T>, mogwai_ids: Vec<T::Hash>) -> DispatchResultWithPostInfo {
 
-			let auctions = Self::auctions_expire_at(block_number);
-			Self::finalize_auctions(auctions);
+			let sender = ensure_signed(origin)?;
 
-			let game_events = Self::game_events_at_block(block_number);
-			Self::finalize_events(block_number, game_events);
+			ensure!(mogwai_ids.len() <= MAX_REAP_KEYS, Error::<T>::MaxReapKeys);
+
+			// nothing is written unless an entry is stale
+			let reaped: u32 = mogwai_ids.into_iter().map(Self::reap_mogwai).sum();
+			ensure!(reaped > 0, Error::<T>::NothingStale);
+
+			// the reward is paid from the pallet account, nothing is paid once it can't cover it
+			let reward = T::ReapReward::get().saturating_mul(reaped.into());
+			let reward = match T::Currency::transfer(&Self::reward_account(), &sender, reward, ExistenceRequirement::KeepAlive) {
+				Ok(()) => reward,
+				Err(_) => Zero::zero(),
+			};
+
+			Self::deposit_event(Event::StaleReaped(sender, reaped, reward));
+
+			Ok(().into())
 		}
 	}
 }
 
-impl<T: Config> Module<T> {
+/// Ensure the origin is signed by the dot mog founder `Key`, can be combined with other origins
+/// to hand founder roles to a council or multisig.
+pub struct EnsureFounder<T>(sp_std::marker::PhantomData<T>);
+
+impl<T: Config> EnsureOrigin<T::Origin> for EnsureFounder<T> {
+	type Success = T::AccountId;
+
+	fn try_origin(o: T::Origin) -> Result<Self::Success, T::Origin> {
+		o.into().and_then(|o| match o {
+			frame_system::RawOrigin::Signed(who) if who == Pallet::<T>::key() => Ok(who),
+			r => Err(T::Origin::from(r)),
+		})
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn successful_origin() -> T::Origin {
+		T::Origin::from(frame_system::RawOrigin::Signed(Pallet::<T>::key()))
+	}
+}
+
+impl<T: Config> Pallet<T> {
 
 	/// Create technical accounts, currently not needed
 	/// This actually does computation. If you need to keep using it, then make sure you cache the
@@ -838,11 +1756,131 @@ impl<T: Config> Module<T> {
 		T::PalletId::get().into_sub_account(mogwai_id)
 	}
 
+	/// The pallet account paying the rewards for reaping stale entries.
+	pub fn reward_account() -> T::AccountId {
+		T::PalletId::get().into_account()
+	}
+
+	/// all mogwais of an account, the owned ones followed by the stashed ones.
+	pub fn mogwais_of(who: T::AccountId) -> Vec<T::Hash> {
+		let owned = (0..Self::owned_mogwais_count(&who)).map(|index| Self::mogwai_of_owner_by_index((who.clone(), index)));
+		let stashed = (0..Self::stashed_mogwais_count(&who)).map(|index| Self::mogwai_of_stash_by_index((who.clone(), index)));
+		owned.chain(stashed).collect()
+	}
+
+	/// a mogwai with its bios, owner, name, auction and pending game events.
+	pub fn mogwai_full(mogwai_id: T::Hash) -> Option<MogwaiFull<T::AccountId, T::Hash, T::BlockNumber, BalanceOf<T>>> {
+		let owner = Self::owner_of(mogwai_id)?;
+		Some(MogwaiFull {
+			mogwai: Self::mogwai(mogwai_id),
+			bios: if MogwaisBios::<T>::contains_key(mogwai_id) { Some(Self::mogwai_bios(mogwai_id)) } else { None },
+			owner,
+			stashed: Self::is_stashed(mogwai_id),
+			name: Self::mogwai_name(mogwai_id).map(|(name, _)| name),
+			auction: Self::auction_of(mogwai_id),
+			lock: Self::lock_of(mogwai_id),
+			pending_events: Self::game_events_of_mogwai(mogwai_id).iter().map(Self::game_events).collect(),
+		})
+	}
+
+	/// the game events executed at the end of a block.
+	pub fn pending_events_at(block_number: T::BlockNumber) -> Vec<GameEvent<T::Hash, T::BlockNumber, GameEventType>> {
+		Self::game_events_at_block(block_number).iter().map(Self::game_events).collect()
+	}
+
+	/// preview a breeding of two mogwais in the current block, `None` if `breed_mogwai` would refuse them.
+	/// in future block mode the breed type isn't known before hatching.
+	pub fn breeding_preview(mogwai_id_1: T::Hash, mogwai_id_2: T::Hash) -> Option<BreedingPreview<BalanceOf<T>>> {
+
+		if mogwai_id_1 == mogwai_id_2 || !Mogwais::<T>::contains_key(mogwai_id_1) || !Mogwais::<T>::contains_key(mogwai_id_2) {
+			return None;
+		}
+
+		let parents = [Self::mogwai(mogwai_id_1), Self::mogwai(mogwai_id_2)];
+		if parents[0].gen + parents[1].gen == 1 ||
+		   (parents[0].gen > 0 && !MogwaisBios::<T>::contains_key(mogwai_id_1)) ||
+		   (parents[1].gen > 0 && !MogwaisBios::<T>::contains_key(mogwai_id_2)) {
+			return None;
+		}
+
+		let breed_type = match Self::randomness_mode() {
+			RandomnessMode::Instant => Some(Self::calculate_breedtype(<frame_system::Pallet<T>>::block_number())),
+			RandomnessMode::FutureBlock => None,
+		};
+		let effects = [Self::adaptation_effects(mogwai_id_1), Self::adaptation_effects(mogwai_id_2)];
+
+		Some(Breeding::preview(
+			breed_type,
+			parents[0].gen, parents[0].rarity,
+			parents[1].gen, parents[1].rarity,
+			&effects[0], &effects[1],
+			&Self::price_table_or_default(PriceType::Pairing),
+		))
+	}
+
+	/// ensure the origin is either root or the dot mog founder.
+	fn ensure_founder_or_root(origin: T::Origin) -> dispatch::DispatchResult {
+		match ensure_signed(origin.clone()) {
+			Ok(sender) => ensure!(sender == Self::key(), Error::<T>::FounderOnly),
+			Err(_) => ensure_root(origin)?,
+		}
+		Ok(())
+	}
+
+	/// check the length bounds and the encoding of a name.
+	fn ensure_valid_name(name: &[u8]) -> dispatch::DispatchResult {
+		ensure!(name.len() >= T::MinNameLength::get() as usize, Error::<T>::NameTooShort);
+		ensure!(name.len() <= T::MaxNameLength::get() as usize, Error::<T>::NameTooLong);
+		ensure!(sp_std::str::from_utf8(name).is_ok(), Error::<T>::NameInvalid);
+		Ok(())
+	}
+
+	/// remove the name of an account and release its deposit.
+	fn clear_name_of_account(who: T::AccountId) -> dispatch::DispatchResult {
+
+		let (name, deposit) = Self::account_name(&who).ok_or(Error::<T>::NameNotFound)?;
+
+		<AccountName<T>>::remove(&who);
+		<AccountOfName<T>>::remove(&name);
+		let _ = T::Currency::unreserve(&who, deposit);
+
+		Self::deposit_event(Event::AccountNameCleared(who));
+
+		Ok(())
+	}
+
+	/// the price at the index of a price table, indices beyond the end use the last entry.
+	pub fn price(price_type: PriceType, index: usize) -> BalanceOf<T> {
+		Pricing::lookup(&Self::price_table_or_default(price_type), index)
+	}
+
+	/// the price table in use, empty tables fall back to the defaults.
+	fn price_table_or_default(price_type: PriceType) -> Vec<BalanceOf<T>> {
+		let table = Self::price_table(price_type);
+		if table.is_empty() {
+			return Pricing::default_table(price_type);
+		}
+		table
+	}
+
+	/// the price to upgrade a configuration to the given level.
+	pub fn config_update_price(config_type: GameConfigType, value: u8) -> BalanceOf<T> {
+		match PriceType::of_config(config_type) {
+			Some(price_type) => Self::price(price_type, value as usize),
+			None => Zero::zero(),
+		}
+	}
+
+	/// check if a game feature isn't disabled.
+	fn is_feature_enabled(feature: GameFeature) -> bool {
+		Self::disabled_features() & feature.flag() == 0
+	}
+
 	/// Reads the nonce from storage, increments the stored nonce, and returns
 	/// the encoded nonce to the caller.
 	fn encode_and_update_nonce() -> Vec<u8> {
-		let nonce = Nonce::get();
-		Nonce::put(nonce.wrapping_add(1));
+		let nonce = Nonce::<T>::get();
+		Nonce::<T>::put(nonce.wrapping_add(1));
 		nonce.encode()
 	}
 
@@ -859,10 +1897,10 @@ impl<T: Config> Module<T> {
 		Ok(())
 	}
 
-	/// pay founder
+	/// pay founder, or the treasury if there is one
 	fn pay_founder(who: T::AccountId, amount: BalanceOf<T>) -> dispatch::DispatchResult {
 
-		let founder: T::AccountId = Self::key();
+		let founder: T::AccountId = Self::treasury().unwrap_or_else(Self::key);
 		let _ =  T::Currency::transfer(
 			&who,
 			&founder,
@@ -876,37 +1914,123 @@ impl<T: Config> Module<T> {
 	/// tiping mogwai
 	fn tip_mogwai(who: T::AccountId, amount: BalanceOf<T>, mogwai_id: T::Hash, mut mogwai_bios:  MogwaiBios<T::Hash, T::BlockNumber, BalanceOf<T>> ) -> dispatch::DispatchResult {
 		
+		let new_intrinsic = mogwai_bios.intrinsic.checked_add(&amount).ok_or(Error::<T>::IntrinsicOverflow)?;
+
 		Self::pay_fee(who, amount)?;
 	  
-		mogwai_bios.intrinsic += amount; // TODO check overflow
+		mogwai_bios.intrinsic = new_intrinsic;
 		<MogwaisBios<T>>::insert(mogwai_id, mogwai_bios);
 
 		Ok(())
 	}
 
-	fn config_value(who: T::AccountId, index: u8) -> u32 {
-		let config_opt = <AccountConfig<T>>::get(&who);
-		let result:u32;
-		if config_opt.is_some() {
-			let value = config_opt.unwrap()[usize::from(index)];
-			result = GameConfig::config_value(index, value);
+	fn config_value(who: T::AccountId, config_type: GameConfigType) -> u32 {
+		let game_config = Self::account_config(&who).unwrap_or_default();
+		GameConfig::config_value(config_type, game_config.get(config_type))
+	}
+
+	fn ensure_not_max_mogwais(who: T::AccountId) -> bool {
+		Self::owned_mogwais_count(&who) < Self::config_value(who.clone(), GameConfigType::MaxMogwaisInAccount) as u64
+	}
+
+	fn ensure_not_max_stash(who: T::AccountId) -> bool {
+		Self::stashed_mogwais_count(&who) < Self::config_value(who.clone(), GameConfigType::MaxStashSize) as u64
+	}
+
+	/// there is either space in the account or in the stash.
+	fn has_space(who: T::AccountId) -> bool {
+		Self::ensure_not_max_mogwais(who.clone()) || Self::ensure_not_max_stash(who)
+	}
+
+	/// number of mogwais that still fit into the account and its stash.
+	fn free_space(who: T::AccountId) -> u64 {
+		let free_owned = (Self::config_value(who.clone(), GameConfigType::MaxMogwaisInAccount) as u64)
+			.saturating_sub(Self::owned_mogwais_count(&who));
+		let free_stash = (Self::config_value(who.clone(), GameConfigType::MaxStashSize) as u64)
+			.saturating_sub(Self::stashed_mogwais_count(&who));
+		free_owned.saturating_add(free_stash)
+	}
+
+	fn is_stashed(mogwai_id: T::Hash) -> bool {
+		<StashedMogwaisIndex<T>>::contains_key(mogwai_id)
+	}
+
+	/// ensure the mogwai isn't locked for a sale, an auction or its hatching.
+	fn ensure_unlocked(mogwai_id: T::Hash) -> dispatch::DispatchResult {
+		ensure!(!MogwaiLocks::<T>::contains_key(mogwai_id), Error::<T>::MogwaiLocked);
+		Ok(())
+	}
+
+	/// add a mogwai to the owned mogwais of an account, or to its stash.
+	fn insert_owned(who: &T::AccountId, mogwai_id: T::Hash, stashed: bool) -> dispatch::DispatchResult {
+
+		if stashed {
+			let stashed_mogwais_count = Self::stashed_mogwais_count(who);
+			let new_stashed_mogwais_count = stashed_mogwais_count.checked_add(1)
+				.ok_or("Overflow adding a mogwai to the stash")?;
+
+			<StashedMogwaisArray<T>>::insert((who.clone(), stashed_mogwais_count), mogwai_id);
+			<StashedMogwaisCount<T>>::insert(who, new_stashed_mogwais_count);
+			<StashedMogwaisIndex<T>>::insert(mogwai_id, stashed_mogwais_count);
 		} else {
-			result = GameConfig::config_value(index, 0);
+			let owned_mogwais_count = Self::owned_mogwais_count(who);
+			let new_owned_mogwais_count = owned_mogwais_count.checked_add(1)
+				.ok_or("Overflow adding a mogwai to account")?;
+
+			<OwnedMogwaisArray<T>>::insert((who.clone(), owned_mogwais_count), mogwai_id);
+			<OwnedMogwaisCount<T>>::insert(who, new_owned_mogwais_count);
+			<OwnedMogwaisIndex<T>>::insert(mogwai_id, owned_mogwais_count);
 		}
-		result
+
+		Ok(())
 	}
 
-	fn ensure_not_max_mogwais(who: T::AccountId) -> bool {
-		Self::owned_mogwais_count(&who) < Self::config_value(who.clone(), 1) as u64
+	/// remove a mogwai from the owned mogwais of an account, or from its stash, using swap and pop.
+	fn remove_owned(who: &T::AccountId, mogwai_id: T::Hash) -> dispatch::DispatchResult {
+
+		if Self::is_stashed(mogwai_id) {
+			let new_stashed_mogwais_count = Self::stashed_mogwais_count(who).checked_sub(1)
+				.ok_or("Overflow removing a mogwai from the stash")?;
+
+			let mogwai_index = <StashedMogwaisIndex<T>>::get(mogwai_id);
+			if mogwai_index != new_stashed_mogwais_count {
+				let last_mogwai_id = <StashedMogwaisArray<T>>::get((who.clone(), new_stashed_mogwais_count));
+				<StashedMogwaisArray<T>>::insert((who.clone(), mogwai_index), last_mogwai_id);
+				<StashedMogwaisIndex<T>>::insert(last_mogwai_id, mogwai_index);
+			}
+
+			<StashedMogwaisArray<T>>::remove((who.clone(), new_stashed_mogwais_count));
+			<StashedMogwaisCount<T>>::insert(who, new_stashed_mogwais_count);
+			<StashedMogwaisIndex<T>>::remove(mogwai_id);
+		} else {
+			let new_owned_mogwais_count = Self::owned_mogwais_count(who).checked_sub(1)
+				.ok_or("Overflow removing a mogwai from account")?;
+
+			// NOTE: This is the "swap and pop" algorithm we have added for you
+			//       We use our storage items to help simplify the removal of elements from the OwnedMogwaisArray
+			//       We switch the last element of OwnedMogwaisArray with the element we want to remove
+			let mogwai_index = <OwnedMogwaisIndex<T>>::get(mogwai_id);
+			if mogwai_index != new_owned_mogwais_count {
+				let last_mogwai_id = <OwnedMogwaisArray<T>>::get((who.clone(), new_owned_mogwais_count));
+				<OwnedMogwaisArray<T>>::insert((who.clone(), mogwai_index), last_mogwai_id);
+				<OwnedMogwaisIndex<T>>::insert(last_mogwai_id, mogwai_index);
+			}
+
+			<OwnedMogwaisArray<T>>::remove((who.clone(), new_owned_mogwais_count));
+			<OwnedMogwaisCount<T>>::insert(who, new_owned_mogwais_count);
+			<OwnedMogwaisIndex<T>>::remove(mogwai_id);
+		}
+
+		Ok(())
 	}
 
 	fn mint(to: T::AccountId, mogwai_id: T::Hash, new_mogwai: MogwaiStruct<T::Hash, T::BlockNumber, BalanceOf<T>, RarityType>, game_event_opt: Option<GameEvent<T::Hash, T::BlockNumber, GameEventType>>) -> dispatch::DispatchResult {
 
 		ensure!(!MogwaiOwner::<T>::contains_key(&mogwai_id), Error::<T>::MogwaiAlreadyExists);
+		ensure!(Self::has_space(to.clone()), Error::<T>::MaxMogwaisInAccount);
 
-		let owned_mogwais_count = Self::owned_mogwais_count(&to);
-		let new_owned_mogwais_count = owned_mogwais_count.checked_add(1)
-			.ok_or("Overflow adding a new mogwai to account balance")?;
+		// once the account is full, new mogwais overflow into the stash
+		let stashed = !Self::ensure_not_max_mogwais(to.clone()) && Self::ensure_not_max_stash(to.clone());
 
 		let all_mogwais_count = Self::all_mogwais_count();
 		let new_all_mogwais_count = all_mogwais_count.checked_add(1)
@@ -922,19 +2046,41 @@ impl<T: Config> Module<T> {
 		<MogwaiOwner<T>>::insert(mogwai_id, &to);
 			
 		<AllMogwaisArray<T>>::insert(all_mogwais_count, mogwai_id);
-        AllMogwaisCount::put(new_all_mogwais_count);
+        AllMogwaisCount::<T>::put(new_all_mogwais_count);
         <AllMogwaisIndex<T>>::insert(mogwai_id, all_mogwais_count);
 			
-		<OwnedMogwaisArray<T>>::insert((to.clone(), owned_mogwais_count), mogwai_id);
-        <OwnedMogwaisCount<T>>::insert(&to, new_owned_mogwais_count);
-        <OwnedMogwaisIndex<T>>::insert(mogwai_id, owned_mogwais_count);
+		Self::insert_owned(&to, mogwai_id, stashed)?;
 
 		// Emit an event.
-		Self::deposit_event(RawEvent::MogwaiCreated(to, mogwai_id));
+		Self::deposit_event(Event::MogwaiCreated(to, mogwai_id));
 
 		Ok(())
 	}
 
+	/// mint a mogwai with a chosen dna, generation and rarity, optionally hatched right away.
+	fn mint_specific(to: T::AccountId, dna: T::Hash, gen: u32, rarity: RarityType, with_bios: bool) -> Result<T::Hash, dispatch::DispatchError> {
+
+		let block_number = <frame_system::Pallet<T>>::block_number();
+		let mogwai_id = Self::generate_random_hash(b"mint_to", to.clone());
+
+		let new_mogwai = MogwaiStruct {
+						id: mogwai_id,
+						dna: dna,
+						genesis: block_number,
+						price: Zero::zero(),
+						gen: gen,
+						rarity: rarity,
+		};
+
+		Self::mint(to, mogwai_id, new_mogwai, None)?;
+
+		if with_bios {
+			Self::hatch_now(mogwai_id);
+		}
+
+		Ok(mogwai_id)
+	}
+
 	fn create_event(to: T::AccountId, new_game_event: GameEvent<T::Hash, T::BlockNumber, GameEventType>) -> dispatch::DispatchResult {
 				
 		let event_id = new_game_event.id.clone();
@@ -964,15 +2110,15 @@ impl<T: Config> Module<T> {
 		<GameEvents<T>>::insert(event_id, new_game_event);
 		
 		<AllGameEventsArray<T>>::insert(all_events_count, event_id);
-        AllGameEventsCount::put(new_all_events_count);
+        AllGameEventsCount::<T>::put(new_all_events_count);
 		<AllGameEventsIndex<T>>::insert(event_id, all_events_count);
 		
 		<GameEventsArray<T>>::insert((event_type.clone(), event_type_events_count), event_id);
-        GameEventsCount::insert(&event_type, new_event_type_events_count);
+        GameEventsCount::<T>::insert(&event_type, new_event_type_events_count);
         <GameEventsIndex<T>>::insert(event_id, event_type_events_count);
 
 		// Emit an event.
-		Self::deposit_event(RawEvent::GameEventCreated(to, event_id));
+		Self::deposit_event(Event::GameEventCreated(to, event_id));
 
 		Ok(())
 	}
@@ -980,28 +2126,30 @@ impl<T: Config> Module<T> {
 	fn remove(from: T::AccountId, mogwai_id: T::Hash) -> dispatch::DispatchResult {
 
 		ensure!(MogwaiOwner::<T>::contains_key(&mogwai_id), Error::<T>::MogwaiDoesntExists);
-		
-		// make sure that there is no pending game event on the mogwai before removing it.
-		if GameEventsOfMogwai::<T>::contains_key(&mogwai_id) {
-			let open_game_events = Self::game_events_of_mogwai(&mogwai_id);
-			ensure!(open_game_events.is_empty(), Error::<T>::MogwaiHasGameEvents);
-		}
-
-		let owned_mogwais_count = Self::owned_mogwais_count(&from);
-		let new_owned_mogwai_count = owned_mogwais_count.checked_sub(1)
-			.ok_or("Overflow removing an old mogwai from account balance")?;
 
 		let all_mogwais_count = Self::all_mogwais_count();
 		let new_all_mogwais_count = all_mogwais_count.checked_sub(1)
 			.ok_or("Overflow removing an old mogwai to total supply")?;
 
+		Self::remove_owned(&from, mogwai_id)?;
+
+		// nothing may point to a removed mogwai, whatever it was locked for
+		Self::cancel_auction(mogwai_id);
+		Self::cancel_game_events(mogwai_id);
+		<MogwaiLocks<T>>::remove(mogwai_id);
+
 		// Update maps.
 		<Mogwais<T>>::remove(mogwai_id);
 		<MogwaisBios<T>>::remove(mogwai_id);
 		<MogwaiOwner<T>>::remove(mogwai_id);
+		<PendingBreeds<T>>::remove(mogwai_id);
+
+		// the name dies with the mogwai, refund the deposit
+		if let Some((name, deposit)) = <MogwaiName<T>>::take(mogwai_id) {
+			<MogwaiOfName<T>>::remove(name);
+			let _ = T::Currency::unreserve(&from, deposit);
+		}
 
-		<GameEventsOfMogwai<T>>::remove(mogwai_id);
-					
         let all_mogwai_index = <AllMogwaisIndex<T>>::get(mogwai_id);
         if all_mogwai_index != new_all_mogwais_count {
             let all_last_mogwai_id = <AllMogwaisArray<T>>::get(new_all_mogwais_count);
@@ -1010,22 +2158,11 @@ impl<T: Config> Module<T> {
 		}
 
 		<AllMogwaisArray<T>>::remove(new_all_mogwais_count);
-		AllMogwaisCount::put(new_all_mogwais_count);
+		AllMogwaisCount::<T>::put(new_all_mogwais_count);
 		<AllMogwaisIndex<T>>::remove(mogwai_id);
 
-        let mogwai_index = <OwnedMogwaisIndex<T>>::get(mogwai_id);
-        if mogwai_index != new_owned_mogwai_count {
-            let last_mogwai_id = <OwnedMogwaisArray<T>>::get((from.clone(), new_owned_mogwai_count));
-            <OwnedMogwaisArray<T>>::insert((from.clone(), mogwai_index), last_mogwai_id);
-            <OwnedMogwaisIndex<T>>::insert(last_mogwai_id, mogwai_index);
-		}
-
-		<OwnedMogwaisArray<T>>::remove((from.clone(), new_owned_mogwai_count));
-		<OwnedMogwaisCount<T>>::insert(&from, new_owned_mogwai_count);
-		<OwnedMogwaisIndex<T>>::remove(mogwai_id);
-
 		// Emit an event.
-		Self::deposit_event(RawEvent::MogwaiRemoved(from, mogwai_id));
+		Self::deposit_event(Event::MogwaiRemoved(from, mogwai_id));
 
 		Ok(())
 	}
@@ -1036,39 +2173,35 @@ impl<T: Config> Module<T> {
 
         ensure!(owner == from, "You don't own this mogwai");
 
-        ensure!(!<MogwaiAuction<T>>::contains_key(mogwai_id), "This mogwai has an open auction.");
+        Self::ensure_unlocked(mogwai_id)?;
 
-        let owned_mogwai_count_from = Self::owned_mogwais_count(&from);
-        let owned_mogwai_count_to = Self::owned_mogwais_count(&to);
+        ensure!(!Self::is_stashed(mogwai_id), Error::<T>::MogwaiStashed);
 
-		let new_owned_mogwai_count_from = owned_mogwai_count_from.checked_sub(1)
-			.ok_or("Overflow removing a mogwai from account")?;
-		let new_owned_mogwai_count_to = owned_mogwai_count_to.checked_add(1)
-			.ok_or("Overflow adding a mogwai to account")?;
+		// ensure that we have enough space
+		ensure!(Self::has_space(to.clone()), Error::<T>::MaxMogwaisInAccount);
 
-        // NOTE: This is the "swap and pop" algorithm we have added for you
-        //       We use our storage items to help simplify the removal of elements from the OwnedMogwaisArray
-        //       We switch the last element of OwnedMogwaisArray with the element we want to remove
-        let mogwai_index = <OwnedMogwaisIndex<T>>::get(mogwai_id);
-        if mogwai_index != new_owned_mogwai_count_from {
-            let last_mogwai_id = <OwnedMogwaisArray<T>>::get((from.clone(), new_owned_mogwai_count_from));
-            <OwnedMogwaisArray<T>>::insert((from.clone(), mogwai_index), last_mogwai_id);
-            <OwnedMogwaisIndex<T>>::insert(last_mogwai_id, mogwai_index);
-        }
+		Self::remove_owned(&from, mogwai_id)?;
+
+		// once the account is full, received mogwais overflow into the stash
+		let stashed = !Self::ensure_not_max_mogwais(to.clone());
+		Self::insert_owned(&to, mogwai_id, stashed)?;
 
-		// Now we can remove this item by removing the last element
 		<MogwaiOwner<T>>::insert(mogwai_id, &to);
-		<OwnedMogwaisIndex<T>>::insert(mogwai_id, owned_mogwai_count_to);
 
-		<OwnedMogwaisArray<T>>::remove((from.clone(), new_owned_mogwai_count_from));
-		<OwnedMogwaisArray<T>>::insert((to.clone(), owned_mogwai_count_to), mogwai_id);
-		
-		// Update the OwnedMogwaisCount for `from` and `to`
-		<OwnedMogwaisCount<T>>::insert(&from, new_owned_mogwai_count_from);
-		<OwnedMogwaisCount<T>>::insert(&to, new_owned_mogwai_count_to);
+		// the name moves with the mogwai if the new owner can reserve a deposit, the old one is refunded
+		if let Some((name, deposit)) = <MogwaiName<T>>::take(mogwai_id) {
+			let _ = T::Currency::unreserve(&from, deposit);
+			let new_deposit = T::MogwaiNameDeposit::get();
+			if T::Currency::reserve(&to, new_deposit).is_ok() {
+				<MogwaiName<T>>::insert(mogwai_id, (name, new_deposit));
+			} else {
+				<MogwaiOfName<T>>::remove(name);
+				Self::deposit_event(Event::MogwaiNameCleared(mogwai_id));
+			}
+		}
 
 		// Emit an event.
-		Self::deposit_event(RawEvent::Transferred(from, to, mogwai_id));
+		Self::deposit_event(Event::Transferred(from, to, mogwai_id));
 
         Ok(())
 	}
@@ -1092,31 +2225,30 @@ impl<T: Config> Module<T> {
 		//let breed_type = BreedType::from_u32((block_number % 4.into()).saturated_into::<u32>());
 		//return breed_type;
 
-		let mod_value : u32 = 80;
-		let modulo80 = (block_number % mod_value.into()).saturated_into::<u32>();
-		if modulo80 < 20 {
-			return BreedType::DomDom;
-		} else if modulo80 < 40 {
-			return BreedType::DomRez;
-		} else if modulo80 < 60 {
-			return BreedType::RezDom;
-		} else {
-			return BreedType::RezRez;
-		}
+		BreedType::of_block(block_number.saturated_into::<u64>())
 	}
 
-	fn finalize_auctions(auctions: Vec<Auction<T::Hash, BalanceOf<T>, T::BlockNumber, T::AccountId>>) -> () {
-			
+	fn finalize_auctions(block_number: T::BlockNumber, auctions: Vec<Auction<T::Hash, BalanceOf<T>, T::BlockNumber, T::AccountId>>) -> () {
+
+		// removing all auctions on this block
+		<Auctions<T>>::remove(block_number);
+
 		for auction in &auctions {
 			let owned_mogwais_count_from = Self::owned_mogwais_count(&auction.mogwai_owner);
 			let owned_mogwais_count_to = Self::owned_mogwais_count(&auction.high_bidder);
 
+			// the mogwai has to be transferable before any currency moves, otherwise the auction is unsold
 			if owned_mogwais_count_to.checked_add(1).is_some() &&
 			   owned_mogwais_count_from.checked_sub(1).is_some() &&
-			   auction.mogwai_owner != auction.high_bidder
+			   auction.mogwai_owner != auction.high_bidder &&
+			   Self::has_space(auction.high_bidder.clone()) &&
+			   Self::owner_of(auction.mogwai_id) == Some(auction.mogwai_owner.clone()) &&
+			   !Self::is_stashed(auction.mogwai_id)
 			{
 				<MogwaiAuction<T>>::remove(auction.mogwai_id);
-				let _ = T::Currency::unreserve(&auction.high_bidder, auction.high_bid);		
+				<MogwaiLocks<T>>::remove(auction.mogwai_id);
+				let _ = T::Currency::unreserve(&auction.high_bidder, auction.high_bid);
+				<Bids<T>>::remove((auction.mogwai_id, auction.high_bidder.clone()));
 				let _currency_transfer = T::Currency::transfer(&auction.high_bidder, &auction.mogwai_owner, auction.high_bid, ExistenceRequirement::AllowDeath);
 				match _currency_transfer {
 					Err(_e) => continue,
@@ -1125,7 +2257,7 @@ impl<T: Config> Module<T> {
 						match _mogwai_transfer {
 							Err(_e) => continue,
 							Ok(_v) => {
-								Self::deposit_event(RawEvent::AuctionFinalized(auction.mogwai_id, auction.high_bid, auction.expiry));
+								Self::deposit_event(Event::AuctionFinalized(auction.mogwai_id, auction.high_bid, auction.expiry));
 							},
 						}
 					},
@@ -1134,7 +2266,10 @@ impl<T: Config> Module<T> {
 		}
 		
 		for auction in &auctions {
-			<Auctions<T>>::remove(<frame_system::Pallet<T>>::block_number());
+			// auctions without a sale release the mogwai as well
+			<MogwaiAuction<T>>::remove(auction.mogwai_id);
+			<MogwaiLocks<T>>::remove(auction.mogwai_id);
+
 			let bid_accounts = Self::bid_accounts(auction.mogwai_id);
 			for account in bid_accounts {
 
@@ -1156,58 +2291,267 @@ impl<T: Config> Module<T> {
 			let game_event = Self::game_events(game_event_hash);
 
 			// clean-up game events
-			<GameEvents<T>>::remove(&game_event.id);
-
-			// remove the game event for all affected mogwais, removing mogwais with pending game events is forbidden
-			// and should be cleared in any function that removes them, like sacrifice and remove.
-			// TODO remove empty entries to avoid storage getting to big.
-			for hash in &game_event.hashes {
-				<GameEventsOfMogwai<T>>::mutate(&hash, |mogwai_game_events| mogwai_game_events.retain(|&x| x != game_event.id));
-				let open_game_events = Self::game_events_of_mogwai(&hash);
-				if open_game_events.is_empty() {
-					<GameEventsOfMogwai<T>>::remove(hash);
-				}
+			Self::clear_game_event(&game_event);
+
+			// finally execute the event at the end of the clean up
+			match game_event.event_type {
+				GameEventType::Hatch => Self::execute_event_hatch(game_event.clone()),
+				GameEventType::Grow => Self::execute_event_grow(game_event.clone()),
+				GameEventType::Mature => Self::execute_event_grow(game_event.clone()),
+				GameEventType::Default => { },
+			};
+
+			Self::deposit_event(Event::GameEventExecuted(game_event.id));
+
+		}
+	}
+
+	/// remove a game event and its indices, the block schedule is left to the caller.
+	fn clear_game_event(game_event: &GameEvent<T::Hash, T::BlockNumber, GameEventType>) -> () {
+
+		<GameEvents<T>>::remove(&game_event.id);
+
+		// remove the game event for all affected mogwais, removing a mogwai cancels its pending game events.
+		for hash in &game_event.hashes {
+			<GameEventsOfMogwai<T>>::mutate(&hash, |mogwai_game_events| mogwai_game_events.retain(|&x| x != game_event.id));
+			let open_game_events = Self::game_events_of_mogwai(&hash);
+			if open_game_events.is_empty() {
+				<GameEventsOfMogwai<T>>::remove(hash);
+			}
+		}
+
+		let all_events_count = Self::all_game_events_count();
+		let all_count_sub_opt = all_events_count.checked_sub(1);
+		if all_count_sub_opt.is_some() {
+			let new_all_events_count = all_count_sub_opt.unwrap();
+			let all_events_index = <AllGameEventsIndex<T>>::get(&game_event.id);
+			if all_events_index != new_all_events_count {
+				let all_last_event = <AllGameEventsArray<T>>::get(new_all_events_count);
+				<AllGameEventsArray<T>>::insert(all_events_index, all_last_event);
+				<AllGameEventsIndex<T>>::insert(all_last_event, all_events_index);
 			}
+			<AllGameEventsArray<T>>::remove(new_all_events_count);
+			AllGameEventsCount::<T>::put(new_all_events_count);
+			<AllGameEventsIndex<T>>::remove(&game_event.id);
+		}
+
+		let event_type_events_count = Self::game_event_of_type_count(&game_event.event_type);
+		let event_count_sub_opt = event_type_events_count.checked_sub(1);
+		if event_count_sub_opt.is_some() {
+			let new_event_type_events_count = event_count_sub_opt.unwrap();
+			let event_index = <GameEventsIndex<T>>::get(&game_event.id);
+			if event_index != new_event_type_events_count {
+				let last_event_id = <GameEventsArray<T>>::get((game_event.event_type.clone(), new_event_type_events_count));
+				<GameEventsArray<T>>::insert((game_event.event_type.clone(), event_index), last_event_id);
+				<GameEventsIndex<T>>::insert(last_event_id, event_index);
+			}
+			<GameEventsArray<T>>::remove((game_event.event_type.clone(), new_event_type_events_count));
+			GameEventsCount::<T>::insert(&game_event.event_type, new_event_type_events_count);
+			<GameEventsIndex<T>>::remove(&game_event.id);
+		}
+	}
+
+	/// ensure there is no pending game event on the mogwai, growth phases are canceled along with the mogwai.
+	fn ensure_no_game_events(mogwai_id: T::Hash) -> dispatch::DispatchResult {
+
+		for game_event_id in Self::game_events_of_mogwai(mogwai_id) {
+			if !GameEvents::<T>::contains_key(game_event_id) {
+				continue;
+			}
+			let game_event = Self::game_events(game_event_id);
+			ensure!(GameEventType::is_growth(game_event.event_type), Error::<T>::MogwaiHasGameEvents);
+		}
+
+		Ok(())
+	}
+
+	/// cancel the pending game events of a mogwai, events shared with other mogwais only lose the mogwai.
+	fn cancel_game_events(mogwai_id: T::Hash) -> () {
+
+		for game_event_id in <GameEventsOfMogwai<T>>::take(mogwai_id) {
+			if !GameEvents::<T>::contains_key(game_event_id) {
+				continue;
+			}
+
+			let mut game_event = Self::game_events(game_event_id);
+			game_event.hashes.retain(|hash| *hash != mogwai_id);
+
+			if !game_event.hashes.is_empty() {
+				<GameEvents<T>>::insert(game_event_id, game_event);
+				continue;
+			}
+
+			let mut block_events = Self::game_events_at_block(game_event.begin);
+			block_events.retain(|id| *id != game_event_id);
+			if block_events.is_empty() {
+				<GameEventsAtBlock<T>>::remove(game_event.begin);
+			} else {
+				<GameEventsAtBlock<T>>::insert(game_event.begin, block_events);
+			}
+
+			Self::clear_game_event(&game_event);
+		}
+	}
+
+	/// cancel the open auction of a mogwai, all bids are returned.
+	fn cancel_auction(mogwai_id: T::Hash) -> () {
+
+		let auction = match <MogwaiAuction<T>>::take(mogwai_id) {
+			Some(auction) => auction,
+			None => return,
+		};
 
-			let all_events_count = Self::all_game_events_count();
-			let all_count_sub_opt = all_events_count.checked_sub(1);
-			if all_count_sub_opt.is_some() {
-				let new_all_events_count = all_count_sub_opt.unwrap();
-				let all_events_index = <AllGameEventsIndex<T>>::get(&game_event.id);
-				if all_events_index != new_all_events_count {
-					let all_last_event = <AllGameEventsArray<T>>::get(new_all_events_count);
-					<AllGameEventsArray<T>>::insert(all_events_index, all_last_event);
-					<AllGameEventsIndex<T>>::insert(all_last_event, all_events_index);
+		let mut auctions = Self::auctions_expire_at(auction.expiry);
+		auctions.retain(|stored_auction| stored_auction.mogwai_id != mogwai_id);
+		if auctions.is_empty() {
+			<Auctions<T>>::remove(auction.expiry);
+		} else {
+			<Auctions<T>>::insert(auction.expiry, auctions);
+		}
+
+		for account in <BidAccounts<T>>::take(mogwai_id) {
+			let bid_balance = <Bids<T>>::take((mogwai_id, account.clone()));
+			let _ = T::Currency::unreserve(&account, bid_balance);
+		}
+
+		Self::deposit_event(Event::AuctionCanceled(mogwai_id));
+	}
+
+	/// remove the auction, bids and game events left behind for a mogwai, returns the number of stale entries.
+	fn reap_mogwai(mogwai_id: T::Hash) -> u32 {
+
+		let exists = Mogwais::<T>::contains_key(mogwai_id);
+		let mut reaped = 0;
+
+		// an auction is stale once it is past its expiry or the mogwai is gone
+		if let Some(auction) = Self::auction_of(mogwai_id) {
+			if !exists || auction.expiry < <frame_system::Pallet<T>>::block_number() {
+				Self::cancel_auction(mogwai_id);
+				if Self::lock_of(mogwai_id) == Some(MogwaiLock::Auctioned) {
+					<MogwaiLocks<T>>::remove(mogwai_id);
 				}
-				<AllGameEventsArray<T>>::remove(new_all_events_count);
-				AllGameEventsCount::put(new_all_events_count);
-				<AllGameEventsIndex<T>>::remove(&game_event.id);
+				reaped += 1;
+			}
+		} else if <BidAccounts<T>>::contains_key(mogwai_id) {
+			for account in <BidAccounts<T>>::take(mogwai_id) {
+				let bid_balance = <Bids<T>>::take((mogwai_id, account.clone()));
+				let _ = T::Currency::unreserve(&account, bid_balance);
 			}
+			reaped += 1;
+		}
 
-			let event_type_events_count = Self::game_event_of_type_count(&game_event.event_type);
-			let event_count_sub_opt = event_type_events_count.checked_sub(1);
-			if event_count_sub_opt.is_some() {
-				let new_event_type_events_count = event_count_sub_opt.unwrap();
-				let event_index = <GameEventsIndex<T>>::get(&game_event.id);
-				if event_index != new_event_type_events_count {
-					let last_event_id = <GameEventsArray<T>>::get((game_event.event_type.clone(), new_event_type_events_count));
-					<GameEventsArray<T>>::insert((game_event.event_type.clone(), event_index), last_event_id);
-					<GameEventsIndex<T>>::insert(last_event_id, event_index);
+		if <GameEventsOfMogwai<T>>::contains_key(mogwai_id) {
+			if !exists {
+				Self::cancel_game_events(mogwai_id);
+				reaped += 1;
+			} else {
+				let game_event_ids = Self::game_events_of_mogwai(mogwai_id);
+				let open_game_event_ids: Vec<T::Hash> = game_event_ids.iter()
+					.filter(|game_event_id| GameEvents::<T>::contains_key(game_event_id))
+					.cloned()
+					.collect();
+				if open_game_event_ids.is_empty() {
+					<GameEventsOfMogwai<T>>::remove(mogwai_id);
+					reaped += 1;
+				} else if open_game_event_ids.len() != game_event_ids.len() {
+					<GameEventsOfMogwai<T>>::insert(mogwai_id, open_game_event_ids);
+					reaped += 1;
 				}
-				<GameEventsArray<T>>::remove((game_event.event_type.clone(), new_event_type_events_count));
-				GameEventsCount::insert(&game_event.event_type, new_event_type_events_count);
-				<GameEventsIndex<T>>::remove(&game_event.id);
 			}
+		}
 
-			// finally execute the event at the end of the clean up
-			match game_event.event_type {
-				GameEventType::Hatch => Self::execute_event_hatch(game_event.clone()),
-				GameEventType::Default => { },
+		reaped
+	}
+
+	/// remove an auction bucket of a past block, reaping the mogwais it holds.
+	fn reap_auctions_at(expiry: T::BlockNumber) -> u32 {
+
+		if expiry >= <frame_system::Pallet<T>>::block_number() {
+			return 0;
+		}
+
+		let reaped = Self::auctions_expire_at(expiry).into_iter()
+			.map(|auction| Self::reap_mogwai(auction.mogwai_id))
+			.sum::<u32>();
+		<Auctions<T>>::remove(expiry);
+
+		reaped.saturating_add(1)
+	}
+
+	/// sweep stale entries with the space left in a block without anything to finalize, unless the sweep is paused.
+	fn sweep_idle(block_number: T::BlockNumber) -> () {
+
+		if block_number < Self::sweep_paused_until() {
+			return;
+		}
+
+		let weight = T::WeightInfo::on_finalize_sweep(MAX_SWEEP_PER_BLOCK);
+		let consumed = <frame_system::Pallet<T>>::block_weight().total();
+		if consumed.saturating_add(weight) > T::BlockWeights::get().max_block {
+			return;
+		}
+
+		Self::sweep_stale(MAX_SWEEP_PER_BLOCK);
+
+		// the sweep wasn't reserved in `on_initialize`, register it now that it fitted into the block
+		<frame_system::Pallet<T>>::register_extra_weight_unchecked(weight, DispatchClass::Mandatory);
+	}
+
+	/// sweep a bounded number of keys of the maps that can hold stale entries, continuing where the last sweep stopped.
+	fn sweep_stale(limit: u32) -> () {
+		use frame_support::storage::StoragePrefixedMap;
+
+		let prefixes = [
+			<MogwaiAuction<T>>::final_prefix(),
+			<BidAccounts<T>>::final_prefix(),
+			<GameEventsOfMogwai<T>>::final_prefix(),
+			<Auctions<T>>::final_prefix(),
+		];
+
+		let (mut map_index, mut cursor, mut reaped) = Self::sweep_cursor();
+		map_index %= prefixes.len() as u8;
+
+		for _ in 0..limit {
+			let prefix = &prefixes[map_index as usize];
+			if cursor.is_empty() {
+				cursor = prefix.to_vec();
+			}
+
+			let key = match sp_io::storage::next_key(&cursor).filter(|key| key.starts_with(prefix)) {
+				Some(key) => key,
+				None => {
+					// the map is swept, go on with the next one
+					map_index = (map_index + 1) % prefixes.len() as u8;
+					cursor = Vec::new();
+					if map_index == 0 {
+						// a full cycle without anything stale pauses the cleanup for a while
+						if reaped == 0 {
+							let block_number = <frame_system::Pallet<T>>::block_number();
+							SweepCursor::<T>::kill();
+							SweepPausedUntil::<T>::put(block_number + SWEEP_PAUSE_BLOCKS.into());
+							return;
+						}
+						reaped = 0;
+					}
+					continue;
+				},
 			};
 
-			Self::deposit_event(RawEvent::GameEventExecuted(game_event.id));
+			// all maps hash their keys with blake2_128_concat, except the game events of a mogwai
+			let hashed_len = if map_index == 2 { 0 } else { 16 };
+			let mut raw_key = key.get(prefix.len() + hashed_len..).unwrap_or_default();
+			if map_index == 3 {
+				if let Ok(expiry) = T::BlockNumber::decode(&mut raw_key) {
+					reaped = reaped.saturating_add(Self::reap_auctions_at(expiry));
+				}
+			} else if let Ok(mogwai_id) = T::Hash::decode(&mut raw_key) {
+				reaped = reaped.saturating_add(Self::reap_mogwai(mogwai_id));
+			}
 
+			cursor = key;
 		}
+
+		SweepCursor::<T>::put((map_index, cursor, reaped));
 	}
 
 	/// TODO: check if it is more optimzed when multiple hatching events are gathered in one event, instead of each in one of it's own.
@@ -1215,28 +2559,219 @@ impl<T: Config> Module<T> {
 
 		for mogwai_id in game_event.hashes.iter() {
 
+			let pending_breed = <PendingBreeds<T>>::take(mogwai_id);
+
+			// hatching releases the offspring
+			if Self::lock_of(mogwai_id) == Some(MogwaiLock::Incubating) {
+				<MogwaiLocks<T>>::remove(mogwai_id);
+			}
+
 			if !Mogwais::<T>::contains_key(mogwai_id) || MogwaisBios::<T>::contains_key(mogwai_id) {
 				// if there is no mogwai or it has already a bios we skip this part, as something bad happend
 				continue;
 			}
 
-			let mogwai_struct = Self::mogwai(mogwai_id);
-			let block_hash = <frame_system::Pallet<T>>::block_hash(mogwai_struct.genesis);
+			let mut mogwai_struct = Self::mogwai(mogwai_id);
+
+			// the genesis block hash is known to the breeder, so it is only used to reproduce the legacy hatching
+			let entropy = if pending_breed.is_none() && Self::hatches_legacy(mogwai_struct.genesis) {
+				<frame_system::Pallet<T>>::block_hash(mogwai_struct.genesis)
+			} else {
+				Self::hatch_entropy(*mogwai_id, game_event.begin)
+			};
+
+			// mogwais bred in future block mode draw their outcome from the hatch block too
+			if let Some(pending_breed) = pending_breed {
+				Self::resolve_breed(&mut mogwai_struct, pending_breed, entropy);
+				<Mogwais<T>>::insert(mogwai_id, &mogwai_struct);
+			}
+
+			let mut mogwai_bio = Self::segment(mogwai_struct, entropy, game_event.begin);
 
-			let mogwai_bio = Self::segment(mogwai_struct, block_hash, game_event.begin);
+			Self::unlock_adaptations(&mut mogwai_bio, AdaptationTrigger::Level, mogwai_bio.level as u32);
+			Self::unlock_adaptations(&mut mogwai_bio, AdaptationTrigger::Event, game_event.event_type.clone() as u32);
 
 			<MogwaisBios<T>>::insert(mogwai_id, mogwai_bio);
+
+			Self::schedule_next_phase(*mogwai_id, game_event.event_type.clone(), game_event.begin);
 		}
 	}
 
-	/// do the segmentation 
-	fn segment(mogwai_struct: MogwaiStruct<T::Hash, T::BlockNumber, BalanceOf<T>, RarityType>, block_hash: T::Hash, phase: T::BlockNumber) -> MogwaiBios<T::Hash, T::BlockNumber, BalanceOf<T>> {
+	/// mogwais bred before the upgrade to the hatch entropy hatch like their breeder expects, all of them if enabled.
+	fn hatches_legacy(genesis: T::BlockNumber) -> bool {
+		Self::legacy_hatching() || Self::legacy_hatching_before().map_or(false, |before| genesis < before)
+	}
+
+	/// entropy unknown before the block, drawn from the randomness source and the parent block hash.
+	fn hatch_entropy(mogwai_id: T::Hash, block_number: T::BlockNumber) -> T::Hash {
+		let (random, _) = T::Randomness::random(&(b"hatch_entropy", mogwai_id).encode());
+		let parent_hash = <frame_system::Pallet<T>>::block_hash(block_number.saturating_sub(One::one()));
+		(random, parent_hash, mogwai_id, block_number).using_encoded(T::Hashing::hash)
+	}
+
+	/// draw the breed type, generation, rarity and dna of a mogwai bred in future block mode.
+	fn resolve_breed(mogwai_struct: &mut MogwaiStruct<T::Hash, T::BlockNumber, BalanceOf<T>, RarityType>, pending_breed: PendingBreed<T::Hash>, entropy: T::Hash) -> () {
+
+		let PendingBreed { gens, rarities, strands, effects, .. } = pending_breed;
+
+		let breed_type = BreedType::of_entropy(entropy.as_ref());
+		let (rarity, next_gen) = Generation::next_gen(gens[0], rarities[0], gens[1], rarities[1], entropy.as_ref(), &effects[0], &effects[1]);
+		let final_dna : [u8;32] = Breeding::pairing(breed_type, strands[0], strands[1], &effects[0], &effects[1]);
+
+		mogwai_struct.dna.as_mut().copy_from_slice(&final_dna);
+		mogwai_struct.gen = next_gen;
+		mogwai_struct.rarity = rarity;
+	}
+
+	/// hatch a mogwai in the current block, without waiting for a hatch event.
+	fn hatch_now(mogwai_id: T::Hash) -> () {
+
+		let block_number = <frame_system::Pallet<T>>::block_number();
+
+		// the hash of the current block isn't known yet, so the legacy hatching uses the parent block hash
+		let entropy = if Self::legacy_hatching() {
+			<frame_system::Pallet<T>>::block_hash(block_number.saturating_sub(One::one()))
+		} else {
+			Self::hatch_entropy(mogwai_id, block_number)
+		};
+
+		let mut mogwai_bio = Self::segment(Self::mogwai(mogwai_id), entropy, block_number);
+
+		Self::unlock_adaptations(&mut mogwai_bio, AdaptationTrigger::Level, mogwai_bio.level as u32);
+		Self::unlock_adaptations(&mut mogwai_bio, AdaptationTrigger::Event, GameEventType::Hatch as u32);
+
+		<MogwaisBios<T>>::insert(mogwai_id, mogwai_bio);
+
+		Self::schedule_next_phase(mogwai_id, GameEventType::Hatch, block_number);
+	}
+
+	/// grow a hatched mogwai into its next phase, raising the level and evolving the metaxy.
+	fn execute_event_grow(game_event: GameEvent<T::Hash, T::BlockNumber, GameEventType>) -> () {
+
+		for mogwai_id in game_event.hashes.iter() {
+
+			if !Mogwais::<T>::contains_key(mogwai_id) || !MogwaisBios::<T>::contains_key(mogwai_id) {
+				// if there is no mogwai or it hasn't hatched yet we skip this part, as something bad happend
+				continue;
+			}
+
+			let mut mogwai_bios = Self::mogwai_bios(mogwai_id);
+			if mogwai_bios.metaxy.is_empty() {
+				continue;
+			}
+
+			// the hash of the current block isn't known yet, so we use the parent block hash
+			let block_hash = <frame_system::Pallet<T>>::block_hash(game_event.begin.saturating_sub(One::one()));
+
+			Self::evolve(&mut mogwai_bios, block_hash, game_event.begin);
+
+			Self::unlock_adaptations(&mut mogwai_bios, AdaptationTrigger::Level, mogwai_bios.level as u32);
+			Self::unlock_adaptations(&mut mogwai_bios, AdaptationTrigger::Event, game_event.event_type.clone() as u32);
+
+			<MogwaisBios<T>>::insert(mogwai_id, mogwai_bios);
+
+			Self::schedule_next_phase(*mogwai_id, game_event.event_type.clone(), game_event.begin);
+		}
+	}
+
+	/// schedule the game event of the following growth phase, if there is any.
+	fn schedule_next_phase(mogwai_id: T::Hash, current_phase: GameEventType, begin: T::BlockNumber) -> () {
+
+		let next_phase = match GameEventType::next_phase(current_phase) {
+			Some(next_phase) => next_phase,
+			None => return,
+		};
+
+		let owner = match Self::owner_of(mogwai_id) {
+			Some(owner) => owner,
+			None => return,
+		};
+
+		let event_id = Self::generate_random_hash(b"grow_event", owner.clone());
+
+		// if the target block is already full, we move the event to the next free block
+		let mut next_begin = begin + GameEventType::time_till(next_phase.clone()).into();
+		for _ in 0..MAX_EVENTS_PER_BLOCK {
+			if Self::game_events_at_block(next_begin).len() < MAX_EVENTS_PER_BLOCK {
+				let mut mogwai_ids: Vec<T::Hash> = Vec::new();
+				mogwai_ids.push(mogwai_id);
+
+				let game_event = GameEvent {
+					id: event_id,
+					begin: next_begin,
+					duration: GameEventType::duration(next_phase.clone()),
+					event_type: next_phase.clone(),
+					hashes: mogwai_ids,
+					value: 0,
+				};
+
+				if Self::create_event(owner, game_event).is_err() {
+					Self::deposit_event(Event::GrowthStalled(mogwai_id, next_phase));
+				}
+				return;
+			}
+			next_begin = next_begin + One::one();
+		}
+
+		Self::deposit_event(Event::GrowthStalled(mogwai_id, next_phase));
+	}
+
+	/// add all adaptations unlocked by the trigger, the mogwai doesn't have yet.
+	fn unlock_adaptations(mogwai_bios: &mut MogwaiBios<T::Hash, T::BlockNumber, BalanceOf<T>>, trigger: AdaptationTrigger, value: u32) -> () {
+
+		for adaptation_id in Self::adaptation_ids() {
+			if mogwai_bios.adaptations.contains(&adaptation_id) {
+				continue;
+			}
+			if let Some(adaptation) = Self::adaptation(adaptation_id) {
+				if adaptation.is_unlocked_by(trigger, value) {
+					mogwai_bios.adaptations.push(adaptation_id);
+					Self::deposit_event(Event::AdaptationGained(mogwai_bios.mogwai_id, adaptation_id));
+				}
+			}
+		}
+	}
+
+	/// the effects of all adaptations of a mogwai.
+	fn adaptation_effects(mogwai_id: T::Hash) -> Vec<AdaptationEffect> {
+
+		if !MogwaisBios::<T>::contains_key(mogwai_id) {
+			return Vec::new();
+		}
+
+		Self::mogwai_bios(mogwai_id).adaptations.iter()
+			.filter_map(|adaptation_id| Self::adaptation(adaptation_id))
+			.map(|adaptation| adaptation.effect)
+			.collect()
+	}
+
+	/// evolve the metaxy of a mogwai and move it into the next phase
+	fn evolve(mogwai_bios: &mut MogwaiBios<T::Hash, T::BlockNumber, BalanceOf<T>>, block_hash: T::Hash, phase: T::BlockNumber) -> () {
+
+		let mut gen: [u8; 32] = Default::default();
+		let mut blk: [u8; 32] = Default::default();
+
+		// the dna strand stays, the evolution strand of the last phase is mixed with the block
+		let last_index = mogwai_bios.metaxy.len() - 1;
+		gen[0..16].copy_from_slice(&mogwai_bios.metaxy[0]);
+		gen[16..32].copy_from_slice(&mogwai_bios.metaxy[last_index]);
+		blk.copy_from_slice(&block_hash.as_ref()[0..32]);
+
+		let (_, evo) = Breeding::segmenting(gen, blk);
+
+		mogwai_bios.metaxy.push(evo);
+		mogwai_bios.phases.push(phase);
+		mogwai_bios.level = mogwai_bios.level.saturating_add(1);
+	}
+
+	/// do the segmentation, the entropy is recorded in the bios so it can be reproduced.
+	fn segment(mogwai_struct: MogwaiStruct<T::Hash, T::BlockNumber, BalanceOf<T>, RarityType>, entropy: T::Hash, phase: T::BlockNumber) -> MogwaiBios<T::Hash, T::BlockNumber, BalanceOf<T>> {
 		
 		let mut dna: [u8; 32] = Default::default();
 		let mut blk: [u8; 32] = Default::default();
 
 		dna.copy_from_slice(&mogwai_struct.dna.as_ref()[0..32]);
-		blk.copy_from_slice(&block_hash.as_ref()[0..32]);
+		blk.copy_from_slice(&entropy.as_ref()[0..32]);
 
 		// segmenting the hatched mogwai
 		let (dna,evo) = Breeding::segmenting(dna,blk);
@@ -1256,6 +2791,98 @@ impl<T: Config> Module<T> {
 			level: 1,
 			phases: phases,
 			adaptations: Vec::new(),
+			entropy: Some(entropy),
+		}
+	}
+
+	/// check that all mirrored indices and counts agree with the stored mogwais and game events.
+	#[cfg(any(feature = "try-runtime", test))]
+	pub fn do_try_state() -> Result<(), &'static str> {
+
+		// all mogwais
+		let all_mogwais_count = Self::all_mogwais_count();
+		ensure!(<Mogwais<T>>::iter().count() as u64 == all_mogwais_count, "AllMogwaisCount differs from the stored mogwais");
+		ensure!(<AllMogwaisArray<T>>::iter().count() as u64 == all_mogwais_count, "AllMogwaisArray differs from AllMogwaisCount");
+		ensure!(<AllMogwaisIndex<T>>::iter().count() as u64 == all_mogwais_count, "AllMogwaisIndex differs from AllMogwaisCount");
+		for index in 0..all_mogwais_count {
+			let mogwai_id = Self::mogwai_by_index(index);
+			ensure!(Mogwais::<T>::contains_key(mogwai_id), "AllMogwaisArray points to a missing mogwai");
+			ensure!(<AllMogwaisIndex<T>>::get(mogwai_id) == index, "AllMogwaisIndex differs from AllMogwaisArray");
+		}
+
+		// owned and stashed mogwais, each mogwai sits in exactly one slot of its owner
+		ensure!(<MogwaiOwner<T>>::iter().count() as u64 == all_mogwais_count, "MogwaiOwner differs from the stored mogwais");
+		for (mogwai_id, owner) in <MogwaiOwner<T>>::iter() {
+			ensure!(Mogwais::<T>::contains_key(mogwai_id), "MogwaiOwner points to a missing mogwai");
+			if Self::is_stashed(mogwai_id) {
+				ensure!(!<OwnedMogwaisIndex<T>>::contains_key(mogwai_id), "mogwai is owned and stashed");
+				let index = <StashedMogwaisIndex<T>>::get(mogwai_id);
+				ensure!(index < Self::stashed_mogwais_count(&owner), "StashedMogwaisIndex exceeds StashedMogwaisCount");
+				ensure!(Self::mogwai_of_stash_by_index((owner, index)) == mogwai_id, "StashedMogwaisArray differs from StashedMogwaisIndex");
+			} else {
+				ensure!(<OwnedMogwaisIndex<T>>::contains_key(mogwai_id), "mogwai is neither owned nor stashed");
+				let index = <OwnedMogwaisIndex<T>>::get(mogwai_id);
+				ensure!(index < Self::owned_mogwais_count(&owner), "OwnedMogwaisIndex exceeds OwnedMogwaisCount");
+				ensure!(Self::mogwai_of_owner_by_index((owner, index)) == mogwai_id, "OwnedMogwaisArray differs from OwnedMogwaisIndex");
+			}
+		}
+
+		let owned_count: u64 = <OwnedMogwaisCount<T>>::iter().map(|(_, count)| count).sum();
+		ensure!(<OwnedMogwaisArray<T>>::iter().count() as u64 == owned_count, "OwnedMogwaisArray differs from OwnedMogwaisCount");
+		ensure!(<OwnedMogwaisIndex<T>>::iter().count() as u64 == owned_count, "OwnedMogwaisIndex differs from OwnedMogwaisCount");
+
+		let stashed_count: u64 = <StashedMogwaisCount<T>>::iter().map(|(_, count)| count).sum();
+		ensure!(<StashedMogwaisArray<T>>::iter().count() as u64 == stashed_count, "StashedMogwaisArray differs from StashedMogwaisCount");
+		ensure!(<StashedMogwaisIndex<T>>::iter().count() as u64 == stashed_count, "StashedMogwaisIndex differs from StashedMogwaisCount");
+		ensure!(owned_count + stashed_count == all_mogwais_count, "owned and stashed mogwais differ from AllMogwaisCount");
+
+		// all game events
+		let all_events_count = Self::all_game_events_count();
+		ensure!(<GameEvents<T>>::iter().count() as u64 == all_events_count, "AllGameEventsCount differs from the stored game events");
+		ensure!(<AllGameEventsArray<T>>::iter().count() as u64 == all_events_count, "AllGameEventsArray differs from AllGameEventsCount");
+		ensure!(<AllGameEventsIndex<T>>::iter().count() as u64 == all_events_count, "AllGameEventsIndex differs from AllGameEventsCount");
+		for index in 0..all_events_count {
+			let event_id = Self::game_event_by_index(index);
+			ensure!(GameEvents::<T>::contains_key(event_id), "AllGameEventsArray points to a missing game event");
+			ensure!(<AllGameEventsIndex<T>>::get(event_id) == index, "AllGameEventsIndex differs from AllGameEventsArray");
+		}
+
+		// game events by type
+		let mut typed_events_count: u64 = 0;
+		for (event_type, count) in <GameEventsCount<T>>::iter() {
+			for index in 0..count {
+				let event_id = Self::game_event_of_type_by_index((event_type.clone(), index));
+				ensure!(GameEvents::<T>::contains_key(event_id), "GameEventsArray points to a missing game event");
+				ensure!(Self::game_events(event_id).event_type == event_type, "GameEventsArray holds a game event of another type");
+				ensure!(<GameEventsIndex<T>>::get(event_id) == index, "GameEventsIndex differs from GameEventsArray");
+			}
+			typed_events_count += count;
+		}
+		ensure!(typed_events_count == all_events_count, "GameEventsCount differs from AllGameEventsCount");
+		ensure!(<GameEventsArray<T>>::iter().count() as u64 == all_events_count, "GameEventsArray differs from GameEventsCount");
+		ensure!(<GameEventsIndex<T>>::iter().count() as u64 == all_events_count, "GameEventsIndex differs from GameEventsCount");
+
+		// game events by block and by mogwai
+		let mut scheduled_events_count: u64 = 0;
+		for (block_number, event_ids) in <GameEventsAtBlock<T>>::iter() {
+			for event_id in event_ids {
+				ensure!(GameEvents::<T>::contains_key(event_id), "GameEventsAtBlock points to a missing game event");
+				ensure!(Self::game_events(event_id).begin == block_number, "GameEventsAtBlock differs from the begin of the game event");
+				scheduled_events_count += 1;
+			}
+		}
+		ensure!(scheduled_events_count == all_events_count, "GameEventsAtBlock differs from AllGameEventsCount");
+
+		let mut mogwai_events_count: u64 = 0;
+		for (mogwai_id, event_ids) in <GameEventsOfMogwai<T>>::iter() {
+			for event_id in event_ids {
+				ensure!(Self::game_events(event_id).hashes.contains(&mogwai_id), "GameEventsOfMogwai points to a game event without the mogwai");
+				mogwai_events_count += 1;
+			}
 		}
+		let event_mogwais_count: u64 = <GameEvents<T>>::iter().map(|(_, game_event)| game_event.hashes.len() as u64).sum();
+		ensure!(mogwai_events_count == event_mogwais_count, "GameEventsOfMogwai differs from the mogwais of the game events");
+
+		Ok(())
 	}
-}
\ No newline at end of file
+}
@@ -0,0 +1,27 @@
+// DOT Mog, Susbstrate Gamification Project with C# .NET Standard & Unity3D
+// Copyright (C) 2020-2021 DOT Mog Team, darkfriend77 & metastar77
+//
+// DOT Mog is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as
+// published by the Free Software Foundation, either version 3 of the
+// License.
+// DOT Mog is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+use frame_support::{RuntimeDebug, codec::{Encode, Decode}};
+#[cfg(feature = "std")]
+use serde::{Serialize, Deserialize};
+
+/// What a mogwai is locked for, a locked mogwai can't be used for anything else until it is released.
+#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum MogwaiLock {
+	/// offered for a direct sale, released when it is bought or the price is set to zero.
+	Listed = 0,
+	/// offered in an auction, released when the auction is finalized.
+	Auctioned = 1,
+	/// bred and waiting for its hatch event.
+	Incubating = 2,
+}
@@ -0,0 +1,181 @@
+// DOT Mog, Susbstrate Gamification Project with C# .NET Standard & Unity3D
+// Copyright (C) 2020-2021 DOT Mog Team, darkfriend77 & metastar77
+//
+// DOT Mog is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as
+// published by the Free Software Foundation, either version 3 of the
+// License.
+// DOT Mog is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+//! Storage migrations of the pallet.
+
+use super::*;
+use frame_support::{storage::migration, traits::PalletInfo};
+
+/// The storage prefix used by the former `decl_storage!` declaration.
+pub const DECL_STORAGE_PREFIX: &[u8] = b"DotMogModule";
+
+/// Run all migrations needed to reach the latest storage layout.
+pub fn migrate<T: Config>(old_prefix: &[u8]) -> Weight {
+
+	let mut weight = move_to_pallet_prefix::<T>(old_prefix);
+
+	if Pallet::<T>::storage_version() == Releases::V1_0_0 {
+		weight = weight.saturating_add(migrate_to_typed_config::<T>());
+	}
+
+	if Pallet::<T>::storage_version() == Releases::V2_0_0 {
+		StorageVersion::<T>::put(Releases::V3_0_0);
+		weight = weight.saturating_add(T::DbWeight::get().writes(1));
+	}
+
+	if Pallet::<T>::storage_version() == Releases::V3_0_0 {
+		weight = weight.saturating_add(migrate_bios_entropy::<T>());
+	}
+
+	if Pallet::<T>::storage_version() == Releases::V4_0_0 {
+		// only the mogwais bred so far keep the bios their breeder expects
+		LegacyHatchingBefore::<T>::put(<frame_system::Pallet<T>>::block_number());
+		StorageVersion::<T>::put(Releases::V5_0_0);
+		weight = weight.saturating_add(T::DbWeight::get().writes(2));
+	}
+
+	if Pallet::<T>::storage_version() == Releases::V5_0_0 {
+		weight = weight.saturating_add(migrate_lifecycle_locks::<T>());
+	}
+
+	weight.saturating_add(T::DbWeight::get().reads(5))
+}
+
+/// Move all storage from the `decl_storage!` prefix to the pallet name of the runtime, all maps keep
+/// their hashers so the keys stay valid. Also removes the leftover template value `Something`.
+pub fn move_to_pallet_prefix<T: Config>(old_prefix: &[u8]) -> Weight {
+
+	let pallet_name = <T as frame_system::Config>::PalletInfo::name::<Pallet<T>>()
+		.expect("dot mog pallet is part of the runtime");
+
+	let mut weight = T::DbWeight::get().writes(1);
+	let _ = migration::take_storage_value::<u32>(old_prefix, b"Something", &[]);
+
+	// a chain that hasn't been migrated still has the founder key under the old prefix
+	if pallet_name.as_bytes() != old_prefix && migration::have_storage_value(old_prefix, b"Key", &[]) {
+		migration::move_pallet(old_prefix, pallet_name.as_bytes());
+		// moving touches every key of the pallet, so we block the rest of the block
+		weight = weight.saturating_add(T::BlockWeights::get().max_block);
+	}
+
+	weight
+}
+
+/// Translate the raw `Vec<u8>` account configurations into the typed `GameConfig`.
+pub fn migrate_to_typed_config<T: Config>() -> Weight {
+
+	let mut translated: u64 = 0;
+	<AccountConfig<T>>::translate::<Vec<u8>, _>(|_, parameters| {
+		translated += 1;
+		Some(GameConfig::from_parameters(&parameters))
+	});
+
+	StorageVersion::<T>::put(Releases::V2_0_0);
+
+	T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+}
+
+/// The mogwai bios before the entropy was recorded.
+#[derive(Decode)]
+struct MogwaiBiosV3<Hash, BlockNumber, Balance> {
+	mogwai_id: Hash,
+	state: u32,
+	metaxy: Vec<[u8;16]>,
+	intrinsic: Balance,
+	level: u8,
+	phases: Vec<BlockNumber>,
+	adaptations: Vec<Hash>,
+}
+
+/// Add the hatching entropy to the mogwai bios, it isn't known for the mogwais hatched so far.
+pub fn migrate_bios_entropy<T: Config>() -> Weight {
+
+	let mut translated: u64 = 0;
+	<MogwaisBios<T>>::translate::<MogwaiBiosV3<T::Hash, T::BlockNumber, BalanceOf<T>>, _>(|_, bios| {
+		translated += 1;
+		Some(MogwaiBios {
+			mogwai_id: bios.mogwai_id,
+			state: bios.state,
+			metaxy: bios.metaxy,
+			intrinsic: bios.intrinsic,
+			level: bios.level,
+			phases: bios.phases,
+			adaptations: bios.adaptations,
+			entropy: None,
+		})
+	});
+
+	StorageVersion::<T>::put(Releases::V4_0_0);
+
+	T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+}
+
+/// Lock the mogwais that are auctioned, incubating or listed, auctions expired without a sale used to be left behind,
+/// they are removed and their bids are returned like `finalize_auctions` does.
+pub fn migrate_lifecycle_locks<T: Config>() -> Weight {
+
+	let block_number = <frame_system::Pallet<T>>::block_number();
+	let mut reads: u64 = 0;
+	let mut writes: u64 = 0;
+
+	let auctions: Vec<_> = <MogwaiAuction<T>>::iter().collect();
+	for (mogwai_id, auction) in auctions {
+		reads += 1;
+		writes += 1;
+		if auction.expiry <= block_number {
+			<MogwaiAuction<T>>::remove(mogwai_id);
+			<Auctions<T>>::remove(auction.expiry);
+
+			let bid_accounts = <Pallet<T>>::bid_accounts(mogwai_id);
+			reads += 1 + bid_accounts.len() as u64;
+			writes += 2 + 2 * bid_accounts.len() as u64;
+			for account in bid_accounts {
+				let bid_balance = <Pallet<T>>::bid_of((mogwai_id, account.clone()));
+				let _ = T::Currency::unreserve(&account, bid_balance);
+				<Bids<T>>::remove((mogwai_id, account));
+			}
+			<BidAccounts<T>>::remove(mogwai_id);
+		} else {
+			<MogwaiLocks<T>>::insert(mogwai_id, MogwaiLock::Auctioned);
+		}
+	}
+
+	for game_event in <GameEvents<T>>::iter_values() {
+		reads += 1;
+		if game_event.event_type != GameEventType::Hatch {
+			continue;
+		}
+		for mogwai_id in game_event.hashes {
+			if !<MogwaiLocks<T>>::contains_key(mogwai_id) {
+				<MogwaiLocks<T>>::insert(mogwai_id, MogwaiLock::Incubating);
+				writes += 1;
+			}
+		}
+	}
+
+	let listed: Vec<_> = <Mogwais<T>>::iter().filter(|(_, mogwai)| !mogwai.price.is_zero()).collect();
+	for (mogwai_id, mut mogwai) in listed {
+		reads += 1;
+		writes += 1;
+		if <MogwaiLocks<T>>::contains_key(mogwai_id) {
+			// a mogwai can't be offered twice, the auction or the hatching wins
+			mogwai.price = Zero::zero();
+			<Mogwais<T>>::insert(mogwai_id, mogwai);
+		} else {
+			<MogwaiLocks<T>>::insert(mogwai_id, MogwaiLock::Listed);
+		}
+	}
+
+	StorageVersion::<T>::put(Releases::V6_0_0);
+
+	T::DbWeight::get().reads_writes(reads + 1, writes + 1)
+}
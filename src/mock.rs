@@ -31,7 +31,16 @@ use sp_runtime::{
 	testing::Header,
 	traits::{BlakeTwo256, IdentityLookup},
 };
-//use frame_system::EnsureSignedBy;
+use frame_system::{EnsureOneOf, EnsureRoot, EnsureSignedBy};
+
+/// Free balance of each endowed test account, enough to pay for pairing prices.
+pub const INITIAL_BALANCE: u64 = 10_000_000_000_000_000;
+
+/// The dot mog founder account.
+pub const FOUNDER: u128 = 10;
+
+/// The account moderating player content.
+pub const MODERATOR: u128 = 11;
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
 type Block = frame_system::mocking::MockBlock<Test>;
@@ -43,7 +52,7 @@ frame_support::construct_runtime!(
 		UncheckedExtrinsic = UncheckedExtrinsic,
 	{
 		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
-		Balances: pallet_balances::{Pallet, Call, Storage, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
 		DotMogModule: pallet_dotmog::{Pallet, Call, Storage, Event<T>, Config<T>},
 	}
 );
@@ -60,6 +69,14 @@ parameter_types! {
 	pub const ExistentialDeposit: u64 = 1;
 
 	pub const DotMogPalletId: PalletId = PalletId(*b"py/dtmog");
+	pub const MinFeedAmount: u64 = 10;
+	pub const MaxIntrinsicPerLevel: u64 = 1_000;
+	pub const AccountNameDeposit: u64 = 100;
+	pub const MinNameLength: u32 = 3;
+	pub const MaxNameLength: u32 = 16;
+	pub const MogwaiNameDeposit: u64 = 50;
+	pub const UniqueMogwaiNames: bool = true;
+	pub const ReapReward: u64 = 5;
 	pub BlockWeights: frame_system::limits::BlockWeights =
 		frame_system::limits::BlockWeights::simple_max(1024);
 }
@@ -67,6 +84,7 @@ parameter_types! {
 ord_parameter_types! {
 	pub const FounderSetAccount: u128 = 1;
 	pub const SuspensionJudgementSetAccount: u128 = 2;
+	pub const ModeratorAccount: u128 = MODERATOR;
 }
 
 impl frame_system::Config for Test {
@@ -111,6 +129,17 @@ impl Config for Test {
 	type Currency = pallet_balances::Pallet<Self>;
 	type Randomness = TestRandomness<Self>;
 	type PricePayment = ();
+	type MinFeedAmount = MinFeedAmount;
+	type MaxIntrinsicPerLevel = MaxIntrinsicPerLevel;
+	type AccountNameDeposit = AccountNameDeposit;
+	type MinNameLength = MinNameLength;
+	type MaxNameLength = MaxNameLength;
+	type MogwaiNameDeposit = MogwaiNameDeposit;
+	type UniqueMogwaiNames = UniqueMogwaiNames;
+	type ReapReward = ReapReward;
+	type AdminOrigin = EnsureOneOf<u128, EnsureRoot<u128>, EnsureFounder<Test>>;
+	type ModeratorOrigin = EnsureOneOf<u128, EnsureRoot<u128>, EnsureSignedBy<ModeratorAccount, u128>>;
+	type WeightInfo = ();
 	//type Scheduler = Scheduler;
 	//type PalletsOrigin = OriginCaller;
 }
@@ -123,15 +152,44 @@ impl Config for Test {
 // This function basically just builds a genesis storage key/value store according to
 // our desired mockup.
 pub fn new_test_ext() -> sp_io::TestExternalities {
+	new_test_ext_with(pallet_dotmog::GenesisConfig {
+		key: FOUNDER,
+		price_tables: Vec::new(),
+		account_configs: Vec::new(),
+		mogwais: Vec::new(),
+		randomness_mode: RandomnessMode::Instant,
+	})
+}
+
+/// Build the genesis storage with a custom dot mog genesis configuration.
+pub fn new_test_ext_with(dotmog_config: pallet_dotmog::GenesisConfig<Test>) -> sp_io::TestExternalities {
 	let t = GenesisConfig {
 	//	// We use default for brevity, but you can configure as desired if needed.
 		frame_system: Default::default(),
-		pallet_dotmog: Default::default(),
+		pallet_balances: pallet_balances::GenesisConfig::<Test> {
+			balances: vec![(1, INITIAL_BALANCE), (2, INITIAL_BALANCE), (3, INITIAL_BALANCE)],
+		},
+		pallet_dotmog: dotmog_config,
 	}.build_storage().unwrap();
 	t.into()
 	//frame_system::GenesisConfig::default().build_storage::<Test>().unwrap().into()
 }
 
+/// Run a test and check the storage invariants once it is done.
+pub trait ExecuteWithChecks {
+	fn execute_with_checks<R>(&mut self, test: impl FnOnce() -> R) -> R;
+}
+
+impl ExecuteWithChecks for sp_io::TestExternalities {
+	fn execute_with_checks<R>(&mut self, test: impl FnOnce() -> R) -> R {
+		self.execute_with(|| {
+			let result = test();
+			DotMogModule::do_try_state().expect("storage invariants hold after the test");
+			result
+		})
+	}
+}
+
 /// Run until a particular block.
 pub fn run_to_block(n: u64) {
 	while System::block_number() < n {
@@ -139,7 +197,9 @@ pub fn run_to_block(n: u64) {
 			System::on_finalize(System::block_number());
 			DotMogModule::on_finalize(System::block_number());
 		}
-		System::set_block_number(System::block_number() + 1);
+		// start the block like the executive does, which also resets the consumed block weight
+		let next = System::block_number() + 1;
+		System::initialize(&next, &System::block_hash(next - 1), &Default::default(), frame_system::InitKind::Inspection);
 		System::on_initialize(System::block_number());
 
 		DotMogModule::on_initialize(System::block_number());
@@ -0,0 +1,94 @@
+// DOT Mog, Susbstrate Gamification Project with C# .NET Standard & Unity3D
+// Copyright (C) 2020-2021 DOT Mog Team, darkfriend77 & metastar77
+//
+// DOT Mog is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as
+// published by the Free Software Foundation, either version 3 of the
+// License.
+// DOT Mog is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+//! The mogwais as non-fungible tokens, all of them in a single class and identified by their hash.
+
+use super::*;
+use frame_support::traits::tokens::nonfungibles::{Inspect, Transfer, Mutate};
+
+impl<T: Config> Inspect<T::AccountId> for Pallet<T> {
+	type InstanceId = T::Hash;
+	type ClassId = ();
+
+	fn owner(_class: &Self::ClassId, instance: &Self::InstanceId) -> Option<T::AccountId> {
+		Self::owner_of(instance)
+	}
+
+	/// the dot mog founder owns the class.
+	fn class_owner(_class: &Self::ClassId) -> Option<T::AccountId> {
+		Some(Self::key())
+	}
+
+	/// the `dna`, `gen`, `rarity` and `level` of a mogwai, eggs are at level zero.
+	fn attribute(_class: &Self::ClassId, instance: &Self::InstanceId, key: &[u8]) -> Option<Vec<u8>> {
+		if !Mogwais::<T>::contains_key(instance) {
+			return None;
+		}
+
+		let mogwai = Self::mogwai(instance);
+		match key {
+			b"dna" => Some(mogwai.dna.encode()),
+			b"gen" => Some(mogwai.gen.encode()),
+			b"rarity" => Some(mogwai.rarity.encode()),
+			b"level" => Some(Self::mogwai_bios(instance).level.encode()),
+			_ => None,
+		}
+	}
+
+	/// locked and stashed mogwais can't be transferred.
+	fn can_transfer(_class: &Self::ClassId, instance: &Self::InstanceId) -> bool {
+		Mogwais::<T>::contains_key(instance) && Self::lock_of(instance).is_none() && !Self::is_stashed(*instance)
+	}
+}
+
+impl<T: Config> Transfer<T::AccountId> for Pallet<T> {
+	fn transfer(_class: &Self::ClassId, instance: &Self::InstanceId, destination: &T::AccountId) -> dispatch::DispatchResult {
+
+		let owner = Self::owner_of(instance).ok_or(Error::<T>::MogwaiDoesntExists)?;
+
+		// ensure that we have enough space
+		ensure!(Self::has_space(destination.clone()), Error::<T>::MaxMogwaisInAccount);
+
+		Self::transfer_from(owner, destination.clone(), *instance)
+	}
+}
+
+impl<T: Config> Mutate<T::AccountId> for Pallet<T> {
+	/// mint a gen 0 mogwai with the instance as dna, like `create_mogwai` does.
+	fn mint_into(_class: &Self::ClassId, instance: &Self::InstanceId, who: &T::AccountId) -> dispatch::DispatchResult {
+
+		ensure!(!Mogwais::<T>::contains_key(instance), Error::<T>::MogwaiAlreadyExists);
+
+		// ensure that we have enough space
+		ensure!(Self::has_space(who.clone()), Error::<T>::MaxMogwaisInAccount);
+
+		let new_mogwai = MogwaiStruct {
+			id: *instance,
+			dna: *instance,
+			genesis: <frame_system::Pallet<T>>::block_number(),
+			price: Zero::zero(),
+			gen: 0,
+			rarity: RarityType::Minor,
+		};
+
+		Self::mint(who.clone(), *instance, new_mogwai, None)
+	}
+
+	/// burn a mogwai, as long as it isn't locked for anything else.
+	fn burn_from(_class: &Self::ClassId, instance: &Self::InstanceId) -> dispatch::DispatchResult {
+
+		let owner = Self::owner_of(instance).ok_or(Error::<T>::MogwaiDoesntExists)?;
+		Self::ensure_unlocked(*instance)?;
+
+		Self::remove(owner, *instance)
+	}
+}
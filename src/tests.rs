@@ -20,32 +20,17 @@
 use super::*;
 use mock::*;
 
-use frame_support::{assert_ok, assert_noop};
-
-#[test]
-fn it_works_for_default_value() {
-	new_test_ext().execute_with(|| {
-		// Dispatch a signed extrinsic.
-		assert_ok!(DotMogModule::do_something(Origin::signed(1), 42));
-		// Read pallet storage and assert an expected result.
-		assert_eq!(DotMogModule::something(), Some(42));
-	});
-}
-
-#[test]
-fn correct_error_for_none_value() {
-	new_test_ext().execute_with(|| {
-		// Ensure the expected error is thrown when no value is present.
-		assert_noop!(
-			DotMogModule::cause_error(Origin::signed(1)),
-			Error::<Test>::NoneValue
-		);
-	});
-}
+use frame_support::{
+	assert_ok, assert_noop, Blake2_128Concat, StorageHasher, traits::OnRuntimeUpgrade,
+	storage::migration::{get_storage_value, have_storage_value, put_storage_value},
+};
+use sp_runtime::traits::BadOrigin;
+use sp_core::H256;
+use proptest::prelude::*;
 
 #[test]
 fn test_dotmog_breeding() {
-	new_test_ext().execute_with(|| {
+	new_test_ext().execute_with_checks(|| {
 		assert_eq!(DotMogModule::all_mogwais_count(), 0);
 		assert_ok!(DotMogModule::create_mogwai(Origin::signed(1)));
 		assert_eq!(DotMogModule::all_mogwais_count(), 1);
@@ -84,11 +69,1359 @@ fn test_dotmog_breeding() {
 		run_to_block(101);
 		assert_eq!(System::block_number(), 101);
 
-		// test if game event triggered
-		assert_eq!(DotMogModule::all_game_events_count(), 0);
+		// test if game event triggered, and the next phase got scheduled
+		assert_eq!(DotMogModule::all_game_events_count(), 1);
 
 		// test if mogwai hatched
 		let mogwai_bios_3 = DotMogModule::mogwai_bios(mogwai_hash_3);
 		assert_eq!(mogwai_bios_3.level, 1);
 	});
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_dotmog_growth() {
+	new_test_ext().execute_with_checks(|| {
+		assert_ok!(DotMogModule::create_mogwai(Origin::signed(1)));
+		assert_ok!(DotMogModule::create_mogwai(Origin::signed(1)));
+		let mogwai_hash_1 = DotMogModule::mogwai_by_index(0);
+		let mogwai_hash_2 = DotMogModule::mogwai_by_index(1);
+
+		assert_ok!(DotMogModule::breed_mogwai(Origin::signed(1), mogwai_hash_1, mogwai_hash_2));
+		let mogwai_hash_3 = DotMogModule::mogwai_by_index(2);
+
+		// hatch
+		run_to_block(101);
+		let mogwai_bios_3 = DotMogModule::mogwai_bios(mogwai_hash_3);
+		assert_eq!(mogwai_bios_3.level, 1);
+		assert_eq!(mogwai_bios_3.phases.len(), 1);
+		assert_eq!(mogwai_bios_3.metaxy.len(), 2);
+		assert_eq!(DotMogModule::game_event_of_type_count(GameEventType::Grow), 1);
+
+		// grow
+		run_to_block(601);
+		let mogwai_bios_3 = DotMogModule::mogwai_bios(mogwai_hash_3);
+		assert_eq!(mogwai_bios_3.level, 2);
+		assert_eq!(mogwai_bios_3.phases, vec![100, 600]);
+		assert_eq!(mogwai_bios_3.metaxy.len(), 3);
+		assert_eq!(DotMogModule::game_event_of_type_count(GameEventType::Grow), 0);
+		assert_eq!(DotMogModule::game_event_of_type_count(GameEventType::Mature), 1);
+
+		// mature
+		run_to_block(1601);
+		let mogwai_bios_3 = DotMogModule::mogwai_bios(mogwai_hash_3);
+		assert_eq!(mogwai_bios_3.level, 3);
+		assert_eq!(mogwai_bios_3.phases, vec![100, 600, 1600]);
+		assert_eq!(mogwai_bios_3.metaxy.len(), 4);
+		assert_eq!(DotMogModule::all_game_events_count(), 0);
+		assert!(DotMogModule::game_events_of_mogwai(mogwai_hash_3).is_empty());
+	});
+}
+
+#[test]
+fn test_dotmog_sacrifice_growing() {
+	new_test_ext().execute_with_checks(|| {
+		assert_ok!(DotMogModule::create_mogwai(Origin::signed(1)));
+		assert_ok!(DotMogModule::create_mogwai(Origin::signed(1)));
+		let mogwai_hash_1 = DotMogModule::mogwai_by_index(0);
+		let mogwai_hash_2 = DotMogModule::mogwai_by_index(1);
+
+		assert_ok!(DotMogModule::breed_mogwai(Origin::signed(1), mogwai_hash_1, mogwai_hash_2));
+		let mogwai_hash_3 = DotMogModule::mogwai_by_index(2);
+		run_to_block(101);
+		assert_eq!(DotMogModule::game_event_of_type_count(GameEventType::Grow), 1);
+
+		// a pending growth phase doesn't hold the mogwai back, it is canceled with it
+		assert_ok!(DotMogModule::sacrifice(Origin::signed(1), mogwai_hash_3));
+		assert!(DotMogModule::owner_of(mogwai_hash_3).is_none());
+		assert_eq!(DotMogModule::game_event_of_type_count(GameEventType::Grow), 0);
+		assert_eq!(DotMogModule::all_game_events_count(), 0);
+	});
+}
+
+#[test]
+fn test_dotmog_feeding() {
+	new_test_ext().execute_with_checks(|| {
+		assert_ok!(DotMogModule::create_mogwai(Origin::signed(1)));
+		assert_ok!(DotMogModule::create_mogwai(Origin::signed(1)));
+		let mogwai_hash_1 = DotMogModule::mogwai_by_index(0);
+		let mogwai_hash_2 = DotMogModule::mogwai_by_index(1);
+
+		// mogwais without bios can't be fed
+		assert_noop!(
+			DotMogModule::feed_mogwai(Origin::signed(1), mogwai_hash_1, 100),
+			Error::<Test>::MogwaiHasNoBios
+		);
+
+		assert_ok!(DotMogModule::breed_mogwai(Origin::signed(1), mogwai_hash_1, mogwai_hash_2));
+		let mogwai_hash_3 = DotMogModule::mogwai_by_index(2);
+		run_to_block(101);
+
+		assert_noop!(
+			DotMogModule::feed_mogwai(Origin::signed(2), mogwai_hash_3, 5),
+			Error::<Test>::FeedAmountTooLow
+		);
+
+		// the mogwai belongs to account 1, anyone can feed it
+		assert_ok!(DotMogModule::feed_mogwai(Origin::signed(2), mogwai_hash_3, 600));
+		assert_eq!(DotMogModule::mogwai_bios(mogwai_hash_3).intrinsic, 600);
+		assert_eq!(Balances::free_balance(2), INITIAL_BALANCE - 600);
+
+		// a level 1 mogwai can't hold more then the cap of one level
+		assert_noop!(
+			DotMogModule::feed_mogwai(Origin::signed(2), mogwai_hash_3, 600),
+			Error::<Test>::MaxIntrinsicReached
+		);
+		assert_ok!(DotMogModule::feed_mogwai(Origin::signed(2), mogwai_hash_3, 400));
+		assert_eq!(DotMogModule::mogwai_bios(mogwai_hash_3).intrinsic, 1_000);
+	});
+}
+
+/// total issuance plus the intrinsic value held by all mogwais.
+fn total_value() -> u64 {
+	let mut intrinsic = 0;
+	for index in 0..DotMogModule::all_mogwais_count() {
+		intrinsic += DotMogModule::mogwai_bios(DotMogModule::mogwai_by_index(index)).intrinsic;
+	}
+	Balances::total_issuance() + intrinsic
+}
+
+/// an operation of the value conservation property, mogwais are picked by their index.
+#[derive(Clone, Debug)]
+enum ValueOp {
+	Feed(u64, u64),
+	Breed(u64, u64),
+	Sacrifice(u64),
+	SacrificeInto(u64, u64),
+	Wait(u64),
+}
+
+fn value_op() -> impl Strategy<Value = ValueOp> {
+	prop_oneof![
+		(0..8u64, 1..2_000u64).prop_map(|(index, amount)| ValueOp::Feed(index, amount)),
+		(0..8u64, 0..8u64).prop_map(|(index_1, index_2)| ValueOp::Breed(index_1, index_2)),
+		(0..8u64).prop_map(ValueOp::Sacrifice),
+		(0..8u64, 0..8u64).prop_map(|(index_1, index_2)| ValueOp::SacrificeInto(index_1, index_2)),
+		(1..600u64).prop_map(ValueOp::Wait),
+	]
+}
+
+/// the value a successful sacrifice burns, the fee of a gen 0 mogwai or the intrinsic value not returned.
+fn sacrifice_burn(mogwai_id: H256) -> u64 {
+	let mogwai_bios = DotMogModule::mogwai_bios(mogwai_id);
+	if DotMogModule::mogwai(mogwai_id).gen == 0 {
+		return DotMogModule::price(PriceType::Fee, FeeType::Remove as usize) + mogwai_bios.intrinsic;
+	}
+	let intrinsic_return = DotMogModule::price(PriceType::IntrinsicReturn, mogwai_bios.phases.len());
+	mogwai_bios.intrinsic - mogwai_bios.intrinsic / intrinsic_return
+}
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(32))]
+
+	#[test]
+	fn test_dotmog_value_conservation(ops in prop::collection::vec(value_op(), 1..16)) {
+		new_test_ext().execute_with_checks(|| {
+			assert_ok!(DotMogModule::create_mogwai(Origin::signed(1)));
+			assert_ok!(DotMogModule::create_mogwai(Origin::signed(1)));
+
+			let mut value = total_value();
+			let mogwai_at = |index: u64| DotMogModule::mogwai_by_index(index % DotMogModule::all_mogwais_count());
+
+			for op in ops {
+				// failing calls must not move any value either
+				match op {
+					ValueOp::Feed(index, amount) => {
+						let _ = DotMogModule::feed_mogwai(Origin::signed(2), mogwai_at(index), amount);
+					},
+					ValueOp::Breed(index_1, index_2) => {
+						let _ = DotMogModule::breed_mogwai(Origin::signed(1), mogwai_at(index_1), mogwai_at(index_2));
+					},
+					ValueOp::Sacrifice(index) => {
+						let mogwai_id = mogwai_at(index);
+						let burn = sacrifice_burn(mogwai_id);
+						if DotMogModule::sacrifice(Origin::signed(1), mogwai_id).is_ok() {
+							value -= burn;
+						}
+					},
+					ValueOp::SacrificeInto(index_1, index_2) => {
+						let _ = DotMogModule::sacrifice_into(Origin::signed(1), mogwai_at(index_1), mogwai_at(index_2));
+					},
+					ValueOp::Wait(blocks) => run_to_block(System::block_number() + blocks),
+				}
+				assert_eq!(total_value(), value);
+
+				if DotMogModule::all_mogwais_count() == 0 {
+					break;
+				}
+			}
+		});
+	}
+}
+
+/// generations and rarities, with the edge cases mixed in.
+fn fuzz_u32() -> impl Strategy<Value = u32> {
+	prop_oneof![Just(0u32), Just(16u32), Just(17u32), Just(u32::MAX), 0..32u32]
+}
+
+fn fuzz_metaxy() -> impl Strategy<Value = Vec<[u8; 16]>> {
+	prop::collection::vec(any::<[u8; 16]>(), 0..3)
+}
+
+fn fuzz_breed_type() -> impl Strategy<Value = BreedType> {
+	prop_oneof![Just(BreedType::DomDom), Just(BreedType::DomRez), Just(BreedType::RezDom), Just(BreedType::RezRez)]
+}
+
+fn fuzz_effects() -> impl Strategy<Value = Vec<AdaptationEffect>> {
+	prop::collection::vec(
+		prop_oneof![Just(AdaptationEffect::None), Just(AdaptationEffect::RarityBoost), Just(AdaptationEffect::GenerationBoost), Just(AdaptationEffect::Dominant)],
+		0..5,
+	)
+}
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(10_000))]
+
+	#[test]
+	fn test_general_sacrifice_never_panics(gen1 in fuzz_u32(), rar1 in fuzz_u32(), metaxy1 in fuzz_metaxy(), gen2 in fuzz_u32(), rar2 in fuzz_u32(), metaxy2 in fuzz_metaxy()) {
+		let gen_jump = Breeding::sacrifice(gen1, rar1, metaxy1, gen2, rar2, metaxy2);
+		if gen2 >= 16 {
+			prop_assert_eq!(gen_jump, 0);
+		}
+	}
+
+	#[test]
+	fn test_general_breeding_never_panics(
+		breed_type in fuzz_breed_type(), dna1 in any::<[u8; 16]>(), dna2 in any::<[u8; 16]>(), block_hash in any::<[u8; 32]>(),
+		effects1 in fuzz_effects(), effects2 in fuzz_effects(),
+	) {
+		let dna = Breeding::pairing(breed_type, dna1, dna2, &effects1, &effects2);
+		let _ = Breeding::segmenting(dna, block_hash);
+	}
+
+	#[test]
+	fn test_general_generation_never_panics(
+		gen1 in fuzz_u32(), rar1 in fuzz_u32(), gen2 in fuzz_u32(), rar2 in fuzz_u32(),
+		random_hash in prop::collection::vec(any::<u8>(), 0..40), effects1 in fuzz_effects(), effects2 in fuzz_effects(),
+	) {
+		let (_, next_gen) = Generation::next_gen(gen1, RarityType::from_u32(rar1), gen2, RarityType::from_u32(rar2), &random_hash, &effects1, &effects2);
+		prop_assert!(next_gen >= 1 && next_gen <= 16);
+	}
+
+	#[test]
+	fn test_general_binary_never_panics(byte in any::<u8>(), other in any::<u8>(), side in 0..2usize) {
+		let _ = general::Binary::get_bit_at(byte, other);
+		let _ = general::Binary::copy_bits(byte, other, side);
+		let _ = general::Binary::add_one(byte, side);
+		let _ = general::Binary::sub_one(byte, side);
+	}
+}
+
+#[test]
+fn test_dotmog_adaptations() {
+	new_test_ext().execute_with_checks(|| {
+		assert_noop!(
+			DotMogModule::add_adaptation(Origin::signed(1), AdaptationTrigger::Level, 1, AdaptationEffect::GenerationBoost),
+			BadOrigin
+		);
+		assert_ok!(DotMogModule::add_adaptation(Origin::signed(FOUNDER), AdaptationTrigger::Level, 1, AdaptationEffect::GenerationBoost));
+		assert_ok!(DotMogModule::add_adaptation(Origin::signed(FOUNDER), AdaptationTrigger::Event, GameEventType::Grow as u32, AdaptationEffect::RarityBoost));
+		let adaptation_ids = DotMogModule::adaptation_ids();
+		assert_eq!(adaptation_ids.len(), 2);
+
+		assert_ok!(DotMogModule::create_mogwai(Origin::signed(1)));
+		assert_ok!(DotMogModule::create_mogwai(Origin::signed(1)));
+		let mogwai_hash_1 = DotMogModule::mogwai_by_index(0);
+		let mogwai_hash_2 = DotMogModule::mogwai_by_index(1);
+		assert_ok!(DotMogModule::breed_mogwai(Origin::signed(1), mogwai_hash_1, mogwai_hash_2));
+		let mogwai_hash_3 = DotMogModule::mogwai_by_index(2);
+
+		// reaching level 1 unlocks the first adaptation
+		run_to_block(101);
+		assert_eq!(DotMogModule::mogwai_bios(mogwai_hash_3).adaptations, vec![adaptation_ids[0]]);
+
+		// surviving the grow event unlocks the second one
+		run_to_block(601);
+		assert_eq!(DotMogModule::mogwai_bios(mogwai_hash_3).adaptations, adaptation_ids);
+	});
+}
+
+#[test]
+fn test_dotmog_update_config() {
+	new_test_ext().execute_with_checks(|| {
+		assert_eq!(DotMogModule::config_value(1, GameConfigType::MaxMogwaisInAccount), 6);
+
+		// only the next level is a valid update
+		assert_noop!(
+			DotMogModule::update_config(Origin::signed(1), GameConfigType::MaxMogwaisInAccount, Some(2)),
+			Error::<Test>::ConfigUpdateInvalid
+		);
+		assert_noop!(
+			DotMogModule::update_config(Origin::signed(1), GameConfigType::Activated, None),
+			Error::<Test>::ConfigUpdateInvalid
+		);
+
+		let price = DotMogModule::config_update_price(GameConfigType::MaxMogwaisInAccount, 1);
+		assert_ok!(DotMogModule::update_config(Origin::signed(1), GameConfigType::MaxMogwaisInAccount, Some(1)));
+		assert_eq!(Balances::free_balance(1), INITIAL_BALANCE - price);
+		assert_eq!(Balances::free_balance(FOUNDER), price);
+		assert_eq!(DotMogModule::account_config(1u128).unwrap().max_mogwais_in_account, 1);
+		assert_eq!(DotMogModule::config_value(1, GameConfigType::MaxMogwaisInAccount), 12);
+	});
+}
+
+#[test]
+fn test_dotmog_migrate_typed_config() {
+	new_test_ext().execute_with_checks(|| {
+		StorageVersion::<Test>::put(Releases::V1_0_0);
+
+		// old raw encoding, the second one is too short and the third one out of bounds
+		frame_support::storage::unhashed::put(&<AccountConfig<Test>>::hashed_key_for(1u128), &vec![0u8, 2, 0, 0, 0, 0, 0, 0]);
+		frame_support::storage::unhashed::put(&<AccountConfig<Test>>::hashed_key_for(2u128), &vec![0u8]);
+		frame_support::storage::unhashed::put(&<AccountConfig<Test>>::hashed_key_for(3u128), &vec![0u8, 9]);
+
+		DotMogModule::on_runtime_upgrade();
+
+		assert_eq!(DotMogModule::storage_version(), Releases::V6_0_0);
+		assert_eq!(DotMogModule::account_config(1u128).unwrap().max_mogwais_in_account, 2);
+		assert_eq!(DotMogModule::account_config(2u128), Some(GameConfig::new()));
+		assert_eq!(DotMogModule::account_config(3u128), Some(GameConfig::new()));
+	});
+}
+
+#[test]
+fn test_dotmog_migrate_pallet_prefix() {
+	// the legacy storage is only partially set up, so the invariants aren't checked
+	new_test_ext().execute_with(|| {
+		// a chain still using the decl storage prefix, while the runtime names the pallet differently
+		let old_prefix: &[u8] = b"OldDotMog";
+		put_storage_value(old_prefix, b"Key", &[], 7u128);
+		put_storage_value(old_prefix, b"Something", &[], 42u32);
+		put_storage_value(old_prefix, b"OwnedMogwaisCount", &Blake2_128Concat::hash(&7u128.encode()), 3u64);
+		put_storage_value(old_prefix, b"StorageVersion", &[], Releases::V2_0_0);
+
+		crate::migration::migrate::<Test>(old_prefix);
+
+		assert_eq!(DotMogModule::key(), 7);
+		assert_eq!(DotMogModule::owned_mogwais_count(7u128), 3);
+		assert_eq!(DotMogModule::storage_version(), Releases::V6_0_0);
+		assert_eq!(get_storage_value::<u32>(b"DotMogModule", b"Something", &[]), None);
+		assert!(!have_storage_value(old_prefix, b"Key", &[]));
+	});
+}
+
+#[test]
+fn test_dotmog_migrate_bios_entropy() {
+	// the legacy storage is only partially set up, so the invariants aren't checked
+	new_test_ext().execute_with(|| {
+		StorageVersion::<Test>::put(Releases::V3_0_0);
+
+		// the bios layout without the entropy
+		let mogwai_hash = H256::repeat_byte(1);
+		let old_bios = (mogwai_hash, 0u32, vec![[7u8; 16], [8u8; 16]], 50u64, 1u8, vec![101u64], Vec::<H256>::new());
+		frame_support::storage::unhashed::put(&<MogwaisBios<Test>>::hashed_key_for(mogwai_hash), &old_bios);
+
+		DotMogModule::on_runtime_upgrade();
+
+		assert_eq!(DotMogModule::storage_version(), Releases::V6_0_0);
+		let bios = DotMogModule::mogwai_bios(mogwai_hash);
+		assert_eq!(bios.metaxy, vec![[7u8; 16], [8u8; 16]]);
+		assert_eq!(bios.intrinsic, 50);
+		assert_eq!(bios.phases, vec![101]);
+		assert_eq!(bios.entropy, None);
+		// only mogwais bred before the upgrade hatch like their breeder expects
+		assert!(!DotMogModule::legacy_hatching());
+		assert_eq!(DotMogModule::legacy_hatching_before(), Some(System::block_number()));
+	});
+}
+
+#[test]
+fn test_dotmog_legacy_hatching_before_upgrade() {
+	new_test_ext().execute_with_checks(|| {
+		run_to_block(1);
+		assert_ok!(DotMogModule::create_mogwai(Origin::signed(1)));
+		assert_ok!(DotMogModule::create_mogwai(Origin::signed(1)));
+		let mogwai_hash_1 = DotMogModule::mogwai_of_owner_by_index((1u128, 0u64));
+		let mogwai_hash_2 = DotMogModule::mogwai_of_owner_by_index((1u128, 1u64));
+		assert_ok!(DotMogModule::breed_mogwai(Origin::signed(1), mogwai_hash_1, mogwai_hash_2));
+		let bred_before = DotMogModule::mogwai_of_owner_by_index((1u128, 2u64));
+		let offspring = DotMogModule::mogwai(bred_before);
+		let known_at_breeding = DotMogModule::segment(offspring.clone(), System::block_hash(offspring.genesis), 101).metaxy;
+
+		run_to_block(5);
+		StorageVersion::<Test>::put(Releases::V4_0_0);
+		DotMogModule::on_runtime_upgrade();
+		assert_eq!(DotMogModule::legacy_hatching_before(), Some(5));
+
+		assert_ok!(DotMogModule::breed_mogwai(Origin::signed(1), mogwai_hash_1, mogwai_hash_2));
+		let bred_after = DotMogModule::mogwai_of_owner_by_index((1u128, 3u64));
+		let genesis_hash = System::block_hash(DotMogModule::mogwai(bred_after).genesis);
+
+		run_to_block(106);
+
+		// the egg bred before the upgrade hatches like its breeder expects, the one bred after doesn't
+		assert_eq!(DotMogModule::mogwai_bios(bred_before).metaxy, known_at_breeding);
+		assert_eq!(DotMogModule::mogwai_bios(bred_before).entropy, Some(System::block_hash(1)));
+		assert_ne!(DotMogModule::mogwai_bios(bred_after).entropy, Some(genesis_hash));
+	});
+}
+
+#[test]
+fn test_dotmog_game_activation() {
+	new_test_ext().execute_with_checks(|| {
+		assert_ok!(DotMogModule::create_mogwai(Origin::signed(1)));
+		assert_ok!(DotMogModule::create_mogwai(Origin::signed(1)));
+		let mogwai_hash_1 = DotMogModule::mogwai_by_index(0);
+		let mogwai_hash_2 = DotMogModule::mogwai_by_index(1);
+
+		assert_ok!(DotMogModule::create_auction(Origin::signed(1), mogwai_hash_1, 100, 10));
+		assert_ok!(DotMogModule::bid_auction(Origin::signed(2), mogwai_hash_1, 200));
+
+		assert_noop!(DotMogModule::set_game_activated(Origin::signed(1), false), BadOrigin);
+		assert_ok!(DotMogModule::set_game_activated(Origin::signed(FOUNDER), false));
+		assert!(!DotMogModule::game_activated());
+
+		assert_noop!(DotMogModule::breed_mogwai(Origin::signed(1), mogwai_hash_1, mogwai_hash_2), Error::<Test>::GameNotActivated);
+		assert_noop!(DotMogModule::morph_mogwai(Origin::signed(1), mogwai_hash_2), Error::<Test>::GameNotActivated);
+		assert_noop!(DotMogModule::set_price(Origin::signed(1), mogwai_hash_2, 100), Error::<Test>::GameNotActivated);
+		assert_noop!(DotMogModule::buy_mogwai(Origin::signed(2), mogwai_hash_2, 100), Error::<Test>::GameNotActivated);
+		assert_noop!(DotMogModule::create_auction(Origin::signed(1), mogwai_hash_2, 100, 10), Error::<Test>::GameNotActivated);
+		assert_noop!(DotMogModule::bid_auction(Origin::signed(3), mogwai_hash_1, 300), Error::<Test>::GameNotActivated);
+		assert_noop!(DotMogModule::feed_mogwai(Origin::signed(3), mogwai_hash_2, 100), Error::<Test>::GameNotActivated);
+
+		// pending auctions are still settled
+		run_to_block(11);
+		assert_eq!(DotMogModule::owner_of(mogwai_hash_1), Some(2));
+		assert_eq!(Balances::free_balance(1), INITIAL_BALANCE + 200);
+
+		assert_ok!(DotMogModule::set_game_activated(Origin::signed(FOUNDER), true));
+		assert_ok!(DotMogModule::morph_mogwai(Origin::signed(1), mogwai_hash_2));
+	});
+}
+
+#[test]
+fn test_dotmog_feature_status() {
+	new_test_ext().execute_with_checks(|| {
+		assert_ok!(DotMogModule::create_mogwai(Origin::signed(1)));
+		assert_ok!(DotMogModule::create_mogwai(Origin::signed(1)));
+		let mogwai_hash_1 = DotMogModule::mogwai_by_index(0);
+		let mogwai_hash_2 = DotMogModule::mogwai_by_index(1);
+
+		assert_noop!(DotMogModule::set_feature_status(Origin::signed(1), GameFeature::Breeding, false), BadOrigin);
+		assert_ok!(DotMogModule::set_feature_status(Origin::signed(FOUNDER), GameFeature::Breeding, false));
+		assert_ok!(DotMogModule::set_feature_status(Origin::root(), GameFeature::Auctions, false));
+		assert_eq!(DotMogModule::disabled_features(), GameFeature::Breeding.flag() | GameFeature::Auctions.flag());
+
+		assert_noop!(DotMogModule::breed_mogwai(Origin::signed(1), mogwai_hash_1, mogwai_hash_2), Error::<Test>::FeatureDisabled);
+		assert_noop!(DotMogModule::create_auction(Origin::signed(1), mogwai_hash_1, 100, 10), Error::<Test>::FeatureDisabled);
+
+		// other features are still available
+		assert_ok!(DotMogModule::set_price(Origin::signed(1), mogwai_hash_1, 100));
+
+		assert_ok!(DotMogModule::set_feature_status(Origin::root(), GameFeature::Breeding, true));
+		assert_eq!(DotMogModule::disabled_features(), GameFeature::Auctions.flag());
+
+		// a listed mogwai has to be withdrawn before breeding
+		assert_noop!(DotMogModule::breed_mogwai(Origin::signed(1), mogwai_hash_1, mogwai_hash_2), Error::<Test>::MogwaiLocked);
+		assert_ok!(DotMogModule::set_price(Origin::signed(1), mogwai_hash_1, 0));
+		assert_ok!(DotMogModule::breed_mogwai(Origin::signed(1), mogwai_hash_1, mogwai_hash_2));
+	});
+}
+
+#[test]
+fn test_dotmog_stash() {
+	new_test_ext().execute_with_checks(|| {
+		for _ in 0..6 {
+			assert_ok!(DotMogModule::create_mogwai(Origin::signed(1)));
+		}
+		assert_eq!(DotMogModule::owned_mogwais_count(1u128), 6);
+
+		// a full account overflows into the stash
+		assert_ok!(DotMogModule::create_mogwai(Origin::signed(1)));
+		assert_eq!(DotMogModule::owned_mogwais_count(1u128), 6);
+		assert_eq!(DotMogModule::stashed_mogwais_count(1u128), 1);
+		let stashed_hash = DotMogModule::mogwai_of_stash_by_index((1u128, 0u64));
+		let mogwai_hash_1 = DotMogModule::mogwai_of_owner_by_index((1u128, 0u64));
+
+		// stashed mogwais can't breed or be sold
+		assert_noop!(DotMogModule::breed_mogwai(Origin::signed(1), mogwai_hash_1, stashed_hash), Error::<Test>::MogwaiStashed);
+		assert_noop!(DotMogModule::set_price(Origin::signed(1), stashed_hash, 100), Error::<Test>::MogwaiStashed);
+		assert_noop!(DotMogModule::create_auction(Origin::signed(1), stashed_hash, 100, 10), Error::<Test>::MogwaiStashed);
+		assert_noop!(DotMogModule::unstash_mogwai(Origin::signed(1), stashed_hash), Error::<Test>::MaxMogwaisInAccount);
+
+		// swap a mogwai of the account with the stashed one
+		assert_ok!(DotMogModule::stash_mogwai(Origin::signed(1), mogwai_hash_1));
+		assert_eq!(DotMogModule::owned_mogwais_count(1u128), 5);
+		assert_eq!(DotMogModule::stashed_mogwais_count(1u128), 2);
+		assert_ok!(DotMogModule::unstash_mogwai(Origin::signed(1), stashed_hash));
+		assert_eq!(DotMogModule::owned_mogwais_count(1u128), 6);
+		assert_eq!(DotMogModule::stashed_mogwais_count(1u128), 1);
+		assert_eq!(DotMogModule::mogwai_of_stash_by_index((1u128, 0u64)), mogwai_hash_1);
+		assert_ok!(DotMogModule::set_price(Origin::signed(1), stashed_hash, 100));
+
+		// fill the stash
+		assert_ok!(DotMogModule::create_mogwai(Origin::signed(1)));
+		assert_ok!(DotMogModule::create_mogwai(Origin::signed(1)));
+		assert_eq!(DotMogModule::stashed_mogwais_count(1u128), 3);
+		assert_noop!(DotMogModule::create_mogwai(Origin::signed(1)), Error::<Test>::MaxMogwaisInAccount);
+
+		// removing a stashed mogwai keeps the stash indices intact
+		assert_ok!(DotMogModule::sacrifice(Origin::signed(1), mogwai_hash_1));
+		assert_eq!(DotMogModule::stashed_mogwais_count(1u128), 2);
+		assert_ne!(DotMogModule::mogwai_of_stash_by_index((1u128, 0u64)), mogwai_hash_1);
+	});
+}
+
+#[test]
+fn test_dotmog_account_naming() {
+	new_test_ext().execute_with_checks(|| {
+		assert_noop!(DotMogModule::set_account_name(Origin::signed(1), b"darkfriend".to_vec()), Error::<Test>::AccountNamingLocked);
+
+		assert_ok!(DotMogModule::update_config(Origin::signed(1), GameConfigType::AccountNaming, None));
+		assert_ok!(DotMogModule::update_config(Origin::signed(2), GameConfigType::AccountNaming, None));
+
+		assert_noop!(DotMogModule::set_account_name(Origin::signed(1), b"df".to_vec()), Error::<Test>::NameTooShort);
+		assert_noop!(DotMogModule::set_account_name(Origin::signed(1), b"darkfriend_darkfriend".to_vec()), Error::<Test>::NameTooLong);
+		assert_noop!(DotMogModule::set_account_name(Origin::signed(1), vec![0xff, 0xfe, 0xfd]), Error::<Test>::NameInvalid);
+
+		assert_ok!(DotMogModule::set_account_name(Origin::signed(1), b"darkfriend".to_vec()));
+		assert_eq!(DotMogModule::account_of_name(b"darkfriend".to_vec()), Some(1));
+		assert_eq!(Balances::reserved_balance(1), 100);
+
+		assert_noop!(DotMogModule::set_account_name(Origin::signed(2), b"darkfriend".to_vec()), Error::<Test>::NameAlreadyTaken);
+
+		// renaming keeps the deposit and frees the old name
+		assert_ok!(DotMogModule::set_account_name(Origin::signed(1), b"metastar".to_vec()));
+		assert_eq!(Balances::reserved_balance(1), 100);
+		assert_eq!(DotMogModule::account_of_name(b"darkfriend".to_vec()), None);
+		assert_ok!(DotMogModule::set_account_name(Origin::signed(2), b"darkfriend".to_vec()));
+
+		// the founder clears offensive names
+		assert_noop!(DotMogModule::force_clear_account_name(Origin::signed(1), 2), BadOrigin);
+		assert_noop!(DotMogModule::force_clear_account_name(Origin::signed(FOUNDER), 2), BadOrigin);
+		assert_ok!(DotMogModule::force_clear_account_name(Origin::signed(MODERATOR), 2));
+		assert_eq!(DotMogModule::account_name(2u128), None);
+		assert_eq!(DotMogModule::account_of_name(b"darkfriend".to_vec()), None);
+		assert_eq!(Balances::reserved_balance(2), 0);
+
+		assert_ok!(DotMogModule::clear_account_name(Origin::signed(1)));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_noop!(DotMogModule::clear_account_name(Origin::signed(1)), Error::<Test>::NameNotFound);
+	});
+}
+
+#[test]
+fn test_dotmog_mogwai_naming() {
+	new_test_ext().execute_with_checks(|| {
+		assert_ok!(DotMogModule::create_mogwai(Origin::signed(1)));
+		assert_ok!(DotMogModule::create_mogwai(Origin::signed(1)));
+		let mogwai_hash_1 = DotMogModule::mogwai_by_index(0);
+		let mogwai_hash_2 = DotMogModule::mogwai_by_index(1);
+
+		assert_noop!(DotMogModule::name_mogwai(Origin::signed(2), mogwai_hash_1, b"gizmo".to_vec()), Error::<Test>::MogwaiNotOwned);
+		assert_noop!(DotMogModule::name_mogwai(Origin::signed(1), mogwai_hash_1, b"gizmo_the_mogwai_one".to_vec()), Error::<Test>::NameTooLong);
+
+		assert_ok!(DotMogModule::name_mogwai(Origin::signed(1), mogwai_hash_1, b"gizmo".to_vec()));
+		assert_eq!(DotMogModule::mogwai_name(mogwai_hash_1), Some((b"gizmo".to_vec(), 50)));
+		assert_eq!(DotMogModule::mogwai_of_name(b"gizmo".to_vec()), Some(mogwai_hash_1));
+		assert_eq!(Balances::reserved_balance(1), 50);
+
+		assert_noop!(DotMogModule::name_mogwai(Origin::signed(1), mogwai_hash_2, b"gizmo".to_vec()), Error::<Test>::NameAlreadyTaken);
+
+		// the name moves with the mogwai, the seller is refunded and the buyer reserves a deposit
+		assert_ok!(DotMogModule::set_price(Origin::signed(1), mogwai_hash_1, 1_000));
+		assert_ok!(DotMogModule::buy_mogwai(Origin::signed(2), mogwai_hash_1, 1_000));
+		assert_eq!(DotMogModule::mogwai_name(mogwai_hash_1), Some((b"gizmo".to_vec(), 50)));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::reserved_balance(2), 50);
+		assert_eq!(Balances::free_balance(1), INITIAL_BALANCE + 1_000);
+
+		// removing the mogwai clears the name and refunds the deposit
+		let fee = DotMogModule::price(PriceType::Fee, FeeType::Remove as usize);
+		assert_ok!(DotMogModule::sacrifice(Origin::signed(2), mogwai_hash_1));
+		assert_eq!(DotMogModule::mogwai_name(mogwai_hash_1), None);
+		assert_eq!(DotMogModule::mogwai_of_name(b"gizmo".to_vec()), None);
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(Balances::free_balance(2), INITIAL_BALANCE - 1_000 - fee);
+
+		// a new owner who can't reserve the deposit drops the name
+		assert_ok!(DotMogModule::name_mogwai(Origin::signed(1), mogwai_hash_2, b"gremlin".to_vec()));
+		assert_ok!(<DotMogModule as frame_support::traits::tokens::nonfungibles::Transfer<u128>>::transfer(&(), &mogwai_hash_2, &5));
+		assert_eq!(DotMogModule::owner_of(mogwai_hash_2), Some(5));
+		assert_eq!(DotMogModule::mogwai_name(mogwai_hash_2), None);
+		assert_eq!(DotMogModule::mogwai_of_name(b"gremlin".to_vec()), None);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), INITIAL_BALANCE + 1_000);
+	});
+}
+
+#[test]
+fn test_dotmog_set_pricing() {
+	new_test_ext().execute_with_checks(|| {
+		// genesis provides the default tables
+		assert_eq!(DotMogModule::price_table(PriceType::IntrinsicReturn), vec![10, 4, 2, 1, 10]);
+		assert_eq!(DotMogModule::price(PriceType::IntrinsicReturn, 7), 10);
+
+		assert_noop!(DotMogModule::set_pricing(Origin::signed(1), PriceType::Fee, vec![1, 2]), BadOrigin);
+		assert_noop!(DotMogModule::set_pricing(Origin::root(), PriceType::Fee, vec![]), Error::<Test>::PriceTableInvalid);
+		assert_noop!(DotMogModule::set_pricing(Origin::root(), PriceType::IntrinsicReturn, vec![10, 0]), Error::<Test>::PriceTableInvalid);
+
+		assert_ok!(DotMogModule::set_pricing(Origin::root(), PriceType::Fee, vec![1, 2]));
+		assert_ok!(DotMogModule::set_pricing(Origin::signed(FOUNDER), PriceType::ConfigMaxMogwais, vec![0, 7]));
+		assert_eq!(DotMogModule::price(PriceType::Fee, FeeType::Remove as usize), 2);
+
+		// the new prices are used right away
+		assert_ok!(DotMogModule::update_config(Origin::signed(1), GameConfigType::MaxMogwaisInAccount, None));
+		assert_eq!(Balances::free_balance(1), INITIAL_BALANCE - 7);
+
+		assert_ok!(DotMogModule::create_mogwai(Origin::signed(1)));
+		assert_ok!(DotMogModule::sacrifice(Origin::signed(1), DotMogModule::mogwai_by_index(0)));
+		assert_eq!(Balances::free_balance(1), INITIAL_BALANCE - 7 - 2);
+	});
+}
+
+#[test]
+fn test_dotmog_founder_roles() {
+	new_test_ext().execute_with_checks(|| {
+		assert_noop!(DotMogModule::set_key(Origin::signed(1), 1), Error::<Test>::FounderOnly);
+
+		// the treasury receives the game payments
+		assert_noop!(DotMogModule::set_treasury(Origin::signed(1), Some(3)), BadOrigin);
+		assert_ok!(DotMogModule::set_treasury(Origin::signed(FOUNDER), Some(3)));
+		let price = DotMogModule::config_update_price(GameConfigType::MaxMogwaisInAccount, 1);
+		assert_ok!(DotMogModule::update_config(Origin::signed(1), GameConfigType::MaxMogwaisInAccount, None));
+		assert_eq!(Balances::free_balance(3), INITIAL_BALANCE + price);
+		assert_eq!(Balances::free_balance(FOUNDER), 0);
+
+		// rotate the key, the old founder loses its powers
+		assert_ok!(DotMogModule::set_key(Origin::signed(FOUNDER), 2));
+		assert_eq!(DotMogModule::key(), 2);
+		assert_noop!(DotMogModule::set_game_activated(Origin::signed(FOUNDER), false), BadOrigin);
+		assert_ok!(DotMogModule::set_game_activated(Origin::signed(2), false));
+
+		// root can always recover the key
+		assert_ok!(DotMogModule::set_key(Origin::root(), FOUNDER));
+		assert_ok!(DotMogModule::set_treasury(Origin::root(), None));
+		assert_eq!(DotMogModule::treasury(), None);
+
+		// only the founder key gives away or removes its own mogwais
+		assert_ok!(DotMogModule::create_mogwai(Origin::signed(1)));
+		let mogwai_hash = DotMogModule::mogwai_by_index(0);
+		assert_noop!(DotMogModule::transfer(Origin::signed(1), 2, mogwai_hash), BadOrigin);
+		assert_noop!(DotMogModule::remove_mogwai(Origin::signed(1), mogwai_hash), BadOrigin);
+		assert_noop!(DotMogModule::remove_mogwai(Origin::root(), mogwai_hash), BadOrigin);
+		assert_noop!(DotMogModule::remove_mogwai(Origin::signed(FOUNDER), mogwai_hash), Error::<Test>::MogwaiNotOwned);
+	});
+}
+
+#[test]
+fn test_dotmog_mint_to() {
+	new_test_ext().execute_with_checks(|| {
+		let dna = H256::repeat_byte(7);
+		assert_noop!(DotMogModule::mint_to(Origin::signed(1), 2, dna, 5, RarityType::Epic, false), BadOrigin);
+		assert_noop!(DotMogModule::mint_to(Origin::signed(FOUNDER), 2, dna, 17, RarityType::Epic, false), Error::<Test>::GenerationInvalid);
+
+		assert_noop!(DotMogModule::mint_to(Origin::signed(FOUNDER), 2, dna, 5, RarityType::Epic, false), Error::<Test>::MintWithoutBios);
+
+		// gen 0 mogwais breed from their dna, they don't need a bios
+		assert_ok!(DotMogModule::mint_to(Origin::signed(FOUNDER), 2, dna, 0, RarityType::Epic, false));
+		let mogwai_hash = DotMogModule::mogwai_of_owner_by_index((2u128, 0u64));
+		let mogwai = DotMogModule::mogwai(mogwai_hash);
+		assert_eq!(mogwai.dna, dna);
+		assert_eq!(mogwai.gen, 0);
+		assert!(mogwai.rarity == RarityType::Epic);
+		assert!(!MogwaisBios::<Test>::contains_key(mogwai_hash));
+
+		// a mogwai with bios is hatched straight away and starts growing
+		assert_ok!(DotMogModule::mint_to(Origin::root(), 2, dna, 1, RarityType::Minor, true));
+		let mogwai_hash = DotMogModule::mogwai_of_owner_by_index((2u128, 1u64));
+		assert_eq!(DotMogModule::mogwai_bios(mogwai_hash).level, 1);
+		assert_eq!(DotMogModule::game_events_of_mogwai(mogwai_hash).len(), 1);
+
+		// the batch is refused as a whole, if an account can't take all mogwais
+		let batch: Vec<(u128, H256, u32, RarityType)> = (0..8).map(|_| (2, dna, 0, RarityType::Rare)).collect();
+		assert_noop!(DotMogModule::mint_to_batch(Origin::signed(FOUNDER), batch.clone(), false), Error::<Test>::MaxMogwaisInAccount);
+		let mut without_bios = batch[1..].to_vec();
+		without_bios[0].2 = 1;
+		assert_noop!(DotMogModule::mint_to_batch(Origin::signed(FOUNDER), without_bios, false), Error::<Test>::MintWithoutBios);
+		assert_ok!(DotMogModule::mint_to_batch(Origin::signed(FOUNDER), batch[1..].to_vec(), false));
+		assert_eq!(DotMogModule::owned_mogwais_count(2u128), 6);
+		assert_eq!(DotMogModule::stashed_mogwais_count(2u128), 3);
+		assert_noop!(DotMogModule::mint_to(Origin::signed(FOUNDER), 2, dna, 0, RarityType::Minor, false), Error::<Test>::MaxMogwaisInAccount);
+
+		let batch: Vec<(u128, H256, u32, RarityType)> = (0..33).map(|_| (3, dna, 1, RarityType::Rare)).collect();
+		assert_noop!(DotMogModule::mint_to_batch(Origin::signed(FOUNDER), batch, false), Error::<Test>::MaxMintBatchSize);
+	});
+}
+
+#[test]
+fn test_dotmog_genesis_mogwais() {
+	let dna = H256::repeat_byte(3);
+	let mogwai = |owner: u128, with_bios: bool, auction: Option<(u64, u64)>| GenesisMogwai {
+		owner, dna, gen: if with_bios { 2 } else { 0 }, rarity: RarityType::Rare, with_bios, auction,
+	};
+
+	let mut account_config = GameConfig::new();
+	account_config.set(GameConfigType::MaxMogwaisInAccount, 1);
+
+	let mut mogwais: Vec<_> = (0..12).map(|_| mogwai(1, false, None)).collect();
+	mogwais.push(mogwai(2, true, Some((100, 10))));
+
+	new_test_ext_with(pallet_dotmog::GenesisConfig {
+		key: FOUNDER,
+		price_tables: Vec::new(),
+		account_configs: vec![(1, account_config.clone())],
+		mogwais,
+		randomness_mode: RandomnessMode::Instant,
+	}).execute_with_checks(|| {
+		assert_eq!(DotMogModule::account_config(1u128), Some(account_config));
+		assert_eq!(DotMogModule::all_mogwais_count(), 13);
+		assert_eq!(DotMogModule::owned_mogwais_count(1u128), 12);
+		assert_eq!(DotMogModule::owned_mogwais_count(2u128), 1);
+
+		for index in 0..12u64 {
+			let mogwai_hash = DotMogModule::mogwai_of_owner_by_index((1u128, index));
+			assert_eq!(DotMogModule::owner_of(mogwai_hash), Some(1));
+			assert_eq!(OwnedMogwaisIndex::<Test>::get(mogwai_hash), index);
+			assert_eq!(DotMogModule::mogwai_by_index(AllMogwaisIndex::<Test>::get(mogwai_hash)), mogwai_hash);
+			assert_eq!(DotMogModule::mogwai(mogwai_hash).gen, 0);
+		}
+
+		let mogwai_hash = DotMogModule::mogwai_of_owner_by_index((2u128, 0u64));
+		assert!(MogwaisBios::<Test>::contains_key(mogwai_hash));
+		assert!(DotMogModule::auction_of(mogwai_hash).is_some());
+		assert_eq!(DotMogModule::auctions_expire_at(10).len(), 1);
+	});
+}
+
+#[test]
+#[should_panic(expected = "genesis mogwai owner has no space left")]
+fn test_dotmog_genesis_mogwais_respect_limits() {
+	let mogwais = (0..10).map(|_| GenesisMogwai {
+		owner: 1, dna: H256::zero(), gen: 0, rarity: RarityType::Minor, with_bios: false, auction: None,
+	}).collect();
+
+	new_test_ext_with(pallet_dotmog::GenesisConfig {
+		key: FOUNDER,
+		price_tables: Vec::new(),
+		account_configs: Vec::new(),
+		mogwais,
+		randomness_mode: RandomnessMode::Instant,
+	});
+}
+
+#[test]
+fn test_dotmog_genesis_serde() {
+	let json = r#"{
+		"owner": 1,
+		"dna": "0x0303030303030303030303030303030303030303030303030303030303030303",
+		"gen": 2,
+		"rarity": "Epic",
+		"withBios": true,
+		"auction": [100, 10]
+	}"#;
+	let mogwai: GenesisMogwai<u128, H256, u64, u64> = serde_json::from_str(json).unwrap();
+	assert_eq!(mogwai.dna, H256::repeat_byte(3));
+	assert!(mogwai.rarity == RarityType::Epic);
+	assert_eq!(mogwai.auction, Some((100, 10)));
+
+	let game_config: GameConfig = serde_json::from_str(r#"{ "maxMogwaisInAccount": 3, "maxStashSize": 1, "accountNaming": 0 }"#).unwrap();
+	assert_eq!(game_config.get(GameConfigType::MaxMogwaisInAccount), 3);
+}
+
+#[test]
+fn test_dotmog_runtime_api_queries() {
+	new_test_ext().execute_with_checks(|| {
+		run_to_block(1);
+		assert_ok!(DotMogModule::mint_to(Origin::signed(FOUNDER), 1, H256::repeat_byte(1), 1, RarityType::Rare, true));
+		assert_ok!(DotMogModule::create_mogwai(Origin::signed(1)));
+		assert_ok!(DotMogModule::create_mogwai(Origin::signed(1)));
+		let hatched = DotMogModule::mogwai_of_owner_by_index((1u128, 0u64));
+		let auctioned = DotMogModule::mogwai_of_owner_by_index((1u128, 1u64));
+		let stashed = DotMogModule::mogwai_of_owner_by_index((1u128, 2u64));
+
+		assert_ok!(DotMogModule::stash_mogwai(Origin::signed(1), stashed));
+		assert_ok!(DotMogModule::name_mogwai(Origin::signed(1), hatched, b"Mogi".to_vec()));
+		assert_ok!(DotMogModule::create_auction(Origin::signed(1), auctioned, 100, 10));
+
+		// owned mogwais come first, the stashed ones follow
+		assert_eq!(DotMogModule::mogwais_of(1), vec![hatched, auctioned, stashed]);
+		assert!(DotMogModule::mogwais_of(2).is_empty());
+
+		let full = DotMogModule::mogwai_full(hatched).unwrap();
+		assert_eq!(full.owner, 1);
+		assert!(!full.stashed);
+		assert_eq!(full.name, Some(b"Mogi".to_vec()));
+		assert_eq!(full.bios.unwrap().level, 1);
+		assert!(full.auction.is_none());
+		assert!(full.lock.is_none());
+		assert_eq!(full.pending_events.len(), 1);
+		assert!(DotMogModule::pending_events_at(full.pending_events[0].begin) == full.pending_events);
+
+		let full = DotMogModule::mogwai_full(auctioned).unwrap();
+		assert!(full.bios.is_none());
+		assert_eq!(full.auction.unwrap().min_bid, 100);
+		assert_eq!(full.lock, Some(MogwaiLock::Auctioned));
+
+		assert!(DotMogModule::mogwai_full(stashed).unwrap().stashed);
+		assert!(DotMogModule::mogwai_full(H256::zero()).is_none());
+		assert!(DotMogModule::pending_events_at(2).is_empty());
+	});
+}
+
+#[test]
+fn test_dotmog_breeding_preview() {
+	new_test_ext().execute_with_checks(|| {
+		run_to_block(25);
+		assert_ok!(DotMogModule::create_mogwai(Origin::signed(1)));
+		assert_ok!(DotMogModule::create_mogwai(Origin::signed(1)));
+		let mogwai_hash_1 = DotMogModule::mogwai_of_owner_by_index((1u128, 0u64));
+		let mogwai_hash_2 = DotMogModule::mogwai_of_owner_by_index((1u128, 1u64));
+
+		// gen 0 mogwais pair for free
+		let preview = DotMogModule::breeding_preview(mogwai_hash_1, mogwai_hash_2).unwrap();
+		assert!(preview.breed_type == Some(BreedType::DomRez));
+		assert_eq!(preview.price, 0);
+		assert_eq!((preview.generation.min_gen, preview.generation.max_gen), (1, 1));
+		assert!(DotMogModule::breeding_preview(mogwai_hash_1, mogwai_hash_1).is_none());
+
+		assert_ok!(DotMogModule::mint_to(Origin::signed(FOUNDER), 1, H256::repeat_byte(1), 2, RarityType::Rare, true));
+		assert_ok!(DotMogModule::mint_to(Origin::signed(FOUNDER), 1, H256::repeat_byte(2), 2, RarityType::Rare, true));
+		let mogwai_hash_3 = DotMogModule::mogwai_of_owner_by_index((1u128, 2u64));
+		let mogwai_hash_4 = DotMogModule::mogwai_of_owner_by_index((1u128, 3u64));
+
+		// the preview matches the breeding in the same block
+		let preview = DotMogModule::breeding_preview(mogwai_hash_3, mogwai_hash_4).unwrap();
+		assert_eq!(preview.price, DotMogModule::price(PriceType::Pairing, 4));
+		let balance = Balances::free_balance(1);
+		assert_ok!(DotMogModule::breed_mogwai(Origin::signed(1), mogwai_hash_3, mogwai_hash_4));
+		assert_eq!(Balances::free_balance(1), balance - preview.price);
+		let mogwai_hash_5 = DotMogModule::mogwai_of_owner_by_index((1u128, 4u64));
+		let offspring = DotMogModule::mogwai(mogwai_hash_5);
+		assert!(offspring.gen >= preview.generation.min_gen && offspring.gen <= preview.generation.max_gen);
+		assert!(offspring.rarity as u32 >= preview.generation.min_rarity as u32);
+		assert!(offspring.rarity as u32 <= preview.generation.max_rarity as u32);
+
+		// mogwais without bios can't be bred, like the offspring before it hatches
+		assert!(offspring.gen > 0);
+		assert!(DotMogModule::breeding_preview(mogwai_hash_1, mogwai_hash_5).is_none());
+	});
+}
+
+#[test]
+fn test_dotmog_next_gen_range() {
+	let parents = [(1, RarityType::Minor), (2, RarityType::Rare), (5, RarityType::Epic), (16, RarityType::Legendary)];
+	let effects = [AdaptationEffect::RarityBoost];
+	for (gen1, rar1) in parents.iter() {
+		for (gen2, rar2) in parents.iter() {
+			let range = Generation::next_gen_range(*gen1, *rar1, *gen2, *rar2, &effects, &[]);
+			for seed in 0..500u32 {
+				let random_hash = sp_io::hashing::blake2_256(&seed.encode());
+				let (rarity, gen) = Generation::next_gen(*gen1, *rar1, *gen2, *rar2, &random_hash, &effects, &[]);
+				assert!(gen >= range.min_gen && gen <= range.max_gen);
+				assert!(rarity as u32 >= range.min_rarity as u32 && rarity as u32 <= range.max_rarity as u32);
+			}
+		}
+	}
+}
+
+#[test]
+fn test_dotmog_future_block_breeding() {
+	let breed = |hatch_parent_hash: H256| new_test_ext().execute_with_checks(|| {
+		assert_noop!(DotMogModule::set_randomness_mode(Origin::signed(1), RandomnessMode::FutureBlock), BadOrigin);
+		assert_ok!(DotMogModule::set_randomness_mode(Origin::signed(FOUNDER), RandomnessMode::FutureBlock));
+
+		run_to_block(1);
+		assert_ok!(DotMogModule::mint_to(Origin::signed(FOUNDER), 1, H256::repeat_byte(1), 2, RarityType::Rare, true));
+		assert_ok!(DotMogModule::mint_to(Origin::signed(FOUNDER), 1, H256::repeat_byte(2), 2, RarityType::Rare, true));
+		let mogwai_hash_1 = DotMogModule::mogwai_of_owner_by_index((1u128, 0u64));
+		let mogwai_hash_2 = DotMogModule::mogwai_of_owner_by_index((1u128, 1u64));
+
+		let preview = DotMogModule::breeding_preview(mogwai_hash_1, mogwai_hash_2).unwrap();
+		assert!(preview.breed_type.is_none());
+
+		assert_ok!(DotMogModule::breed_mogwai(Origin::signed(1), mogwai_hash_1, mogwai_hash_2));
+		let offspring_hash = DotMogModule::mogwai_of_owner_by_index((1u128, 2u64));
+
+		// nothing but the lower bound of the outcome is known after breeding
+		let offspring = DotMogModule::mogwai(offspring_hash);
+		assert_eq!(offspring.dna, H256::zero());
+		assert_eq!((offspring.gen, offspring.rarity), (preview.generation.min_gen, preview.generation.min_rarity));
+		assert!(DotMogModule::pending_breed(offspring_hash).is_some());
+
+		// the parent of the hatch block is only produced after the breeding
+		frame_system::BlockHash::<Test>::insert(100, hatch_parent_hash);
+		run_to_block(102);
+
+		assert!(DotMogModule::pending_breed(offspring_hash).is_none());
+		let offspring = DotMogModule::mogwai(offspring_hash);
+		let bios = DotMogModule::mogwai_bios(offspring_hash);
+		assert_ne!(offspring.dna, H256::zero());
+		assert!(offspring.gen >= preview.generation.min_gen && offspring.gen <= preview.generation.max_gen);
+		assert_eq!(bios.level, 1);
+		(bios.metaxy, bios.entropy.unwrap())
+	});
+
+	let (metaxy_1, entropy_1) = breed(H256::repeat_byte(1));
+	let (metaxy_2, entropy_2) = breed(H256::repeat_byte(2));
+	assert_ne!(entropy_1, entropy_2);
+	assert_ne!(metaxy_1, metaxy_2);
+}
+
+#[test]
+fn test_dotmog_hatch_entropy() {
+	let hatch = |legacy: bool, hatch_parent_hash: H256| new_test_ext().execute_with_checks(|| {
+		assert_noop!(DotMogModule::set_legacy_hatching(Origin::signed(1), legacy), BadOrigin);
+		assert_ok!(DotMogModule::set_legacy_hatching(Origin::signed(FOUNDER), legacy));
+
+		run_to_block(1);
+		assert_ok!(DotMogModule::create_mogwai(Origin::signed(1)));
+		assert_ok!(DotMogModule::create_mogwai(Origin::signed(1)));
+		let mogwai_hash_1 = DotMogModule::mogwai_of_owner_by_index((1u128, 0u64));
+		let mogwai_hash_2 = DotMogModule::mogwai_of_owner_by_index((1u128, 1u64));
+		assert_ok!(DotMogModule::breed_mogwai(Origin::signed(1), mogwai_hash_1, mogwai_hash_2));
+		let offspring_hash = DotMogModule::mogwai_of_owner_by_index((1u128, 2u64));
+
+		// the bios the breeder can compute when submitting the breeding
+		let offspring = DotMogModule::mogwai(offspring_hash);
+		let known_at_breeding = DotMogModule::segment(offspring.clone(), System::block_hash(offspring.genesis), 101).metaxy;
+
+		// the parent of the hatch block is only produced after the breeding
+		frame_system::BlockHash::<Test>::insert(100, hatch_parent_hash);
+		run_to_block(102);
+
+		let bios = DotMogModule::mogwai_bios(offspring_hash);
+		assert_eq!(bios.level, 1);
+		(bios.metaxy.clone() == known_at_breeding, bios.metaxy, bios.entropy.unwrap())
+	});
+
+	// the legacy hatching stays reproducible from the breeding block
+	let (known, metaxy_1, _) = hatch(true, H256::repeat_byte(1));
+	assert!(known);
+	let (known, metaxy_2, _) = hatch(true, H256::repeat_byte(2));
+	assert!(known);
+	assert_eq!(metaxy_1, metaxy_2);
+
+	// otherwise the metaxy depends on the hatch block
+	let (known, metaxy_1, entropy_1) = hatch(false, H256::repeat_byte(1));
+	assert!(!known);
+	let (known, metaxy_2, entropy_2) = hatch(false, H256::repeat_byte(2));
+	assert!(!known);
+	assert_ne!(entropy_1, entropy_2);
+	assert_ne!(metaxy_1, metaxy_2);
+}
+
+#[test]
+fn test_dotmog_auction_bidders() {
+	new_test_ext().execute_with_checks(|| {
+		run_to_block(1);
+		assert_ok!(DotMogModule::create_mogwai(Origin::signed(1)));
+		let mogwai_hash = DotMogModule::mogwai_by_index(0);
+		assert_ok!(DotMogModule::create_auction(Origin::signed(1), mogwai_hash, 100, 10));
+
+		// raising a bid keeps a single entry for the bidder
+		assert_ok!(DotMogModule::bid_auction(Origin::signed(2), mogwai_hash, 200));
+		assert_ok!(DotMogModule::bid_auction(Origin::signed(3), mogwai_hash, 300));
+		assert_ok!(DotMogModule::bid_auction(Origin::signed(2), mogwai_hash, 400));
+		assert_eq!(DotMogModule::bid_accounts(mogwai_hash), vec![2, 3]);
+		assert_eq!(Balances::reserved_balance(2), 400);
+
+		// the bidders are capped, known bidders can still raise
+		for bidder in 0..(MAX_BIDDERS_PER_AUCTION as u128 - 2) {
+			let _ = Balances::deposit_creating(&(100 + bidder), INITIAL_BALANCE);
+			assert_ok!(DotMogModule::bid_auction(Origin::signed(100 + bidder), mogwai_hash, 500 + bidder as u64));
+		}
+		let _ = Balances::deposit_creating(&99, INITIAL_BALANCE);
+		assert_noop!(DotMogModule::bid_auction(Origin::signed(99), mogwai_hash, 1_000), Error::<Test>::MaxBiddersReached);
+		assert_ok!(DotMogModule::bid_auction(Origin::signed(3), mogwai_hash, 1_000));
+		assert_eq!(DotMogModule::bid_accounts(mogwai_hash).len(), MAX_BIDDERS_PER_AUCTION);
+	});
+}
+
+#[test]
+fn test_dotmog_auction_full_winner() {
+	new_test_ext().execute_with_checks(|| {
+		run_to_block(1);
+		assert_ok!(DotMogModule::create_mogwai(Origin::signed(1)));
+		let mogwai_hash = DotMogModule::mogwai_by_index(0);
+		assert_ok!(DotMogModule::create_auction(Origin::signed(1), mogwai_hash, 100, 10));
+		assert_ok!(DotMogModule::bid_auction(Origin::signed(2), mogwai_hash, 200));
+
+		// the winner fills the account and the stash before the auction expires
+		for _ in 0..9 {
+			assert_ok!(DotMogModule::create_mogwai(Origin::signed(2)));
+		}
+		assert_eq!(DotMogModule::owned_mogwais_count(2u128), 6);
+		assert_eq!(DotMogModule::stashed_mogwais_count(2u128), 3);
+
+		// the auction stays unsold and the bid is returned
+		run_to_block(11);
+		assert_eq!(DotMogModule::owner_of(mogwai_hash), Some(1));
+		assert!(DotMogModule::lock_of(mogwai_hash).is_none());
+		assert_eq!(DotMogModule::owned_mogwais_count(2u128), 6);
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(Balances::free_balance(1), INITIAL_BALANCE);
+	});
+}
+
+#[test]
+fn test_dotmog_auction_lock() {
+	new_test_ext().execute_with_checks(|| {
+		run_to_block(1);
+		assert_ok!(DotMogModule::mint_to(Origin::signed(FOUNDER), 1, H256::repeat_byte(1), 1, RarityType::Rare, true));
+		assert_ok!(DotMogModule::mint_to(Origin::signed(FOUNDER), 1, H256::repeat_byte(2), 1, RarityType::Rare, true));
+		let mogwai_hash_1 = DotMogModule::mogwai_of_owner_by_index((1u128, 0u64));
+		let mogwai_hash_2 = DotMogModule::mogwai_of_owner_by_index((1u128, 1u64));
+
+		assert_ok!(DotMogModule::create_auction(Origin::signed(1), mogwai_hash_1, 100, 10));
+		assert_eq!(DotMogModule::lock_of(mogwai_hash_1), Some(MogwaiLock::Auctioned));
+
+		// an auctioned mogwai can't be used for anything else
+		assert_noop!(DotMogModule::breed_mogwai(Origin::signed(1), mogwai_hash_1, mogwai_hash_2), Error::<Test>::MogwaiLocked);
+		assert_noop!(DotMogModule::breed_mogwai(Origin::signed(1), mogwai_hash_2, mogwai_hash_1), Error::<Test>::MogwaiLocked);
+		assert_noop!(DotMogModule::morph_mogwai(Origin::signed(1), mogwai_hash_1), Error::<Test>::MogwaiLocked);
+		assert_noop!(DotMogModule::sacrifice(Origin::signed(1), mogwai_hash_1), Error::<Test>::MogwaiLocked);
+		assert_noop!(DotMogModule::sacrifice_into(Origin::signed(1), mogwai_hash_1, mogwai_hash_2), Error::<Test>::MogwaiLocked);
+		assert_noop!(DotMogModule::stash_mogwai(Origin::signed(1), mogwai_hash_1), Error::<Test>::MogwaiLocked);
+		assert_noop!(DotMogModule::name_mogwai(Origin::signed(1), mogwai_hash_1, b"Mogi".to_vec()), Error::<Test>::MogwaiLocked);
+		assert_noop!(DotMogModule::set_price(Origin::signed(1), mogwai_hash_1, 100), Error::<Test>::MogwaiLocked);
+		assert_noop!(DotMogModule::create_auction(Origin::signed(1), mogwai_hash_1, 100, 20), Error::<Test>::MogwaiLocked);
+		assert_noop!(DotMogModule::buy_mogwai(Origin::signed(2), mogwai_hash_1, 100), "You can't buy this mogwai, there is no price");
+
+		// an auction without bids releases the mogwai at its expiry
+		run_to_block(11);
+		assert!(DotMogModule::auction_of(mogwai_hash_1).is_none());
+		assert!(DotMogModule::lock_of(mogwai_hash_1).is_none());
+		assert_ok!(DotMogModule::set_price(Origin::signed(1), mogwai_hash_1, 100));
+	});
+}
+
+#[test]
+fn test_dotmog_listing_lock() {
+	new_test_ext().execute_with_checks(|| {
+		assert_ok!(DotMogModule::create_mogwai(Origin::signed(1)));
+		assert_ok!(DotMogModule::create_mogwai(Origin::signed(1)));
+		let mogwai_hash_1 = DotMogModule::mogwai_by_index(0);
+		let mogwai_hash_2 = DotMogModule::mogwai_by_index(1);
+
+		assert_ok!(DotMogModule::set_price(Origin::signed(1), mogwai_hash_1, 1_000));
+		assert_eq!(DotMogModule::lock_of(mogwai_hash_1), Some(MogwaiLock::Listed));
+		assert_noop!(DotMogModule::create_auction(Origin::signed(1), mogwai_hash_1, 100, 10), Error::<Test>::MogwaiLocked);
+
+		// the price of a listing can change, zero withdraws it
+		assert_ok!(DotMogModule::set_price(Origin::signed(1), mogwai_hash_1, 500));
+		assert_ok!(DotMogModule::set_price(Origin::signed(1), mogwai_hash_1, 0));
+		assert!(DotMogModule::lock_of(mogwai_hash_1).is_none());
+		assert_noop!(DotMogModule::buy_mogwai(Origin::signed(2), mogwai_hash_1, 1_000), "You can't buy this mogwai, there is no price");
+
+		// buying releases the mogwai for its new owner
+		assert_ok!(DotMogModule::set_price(Origin::signed(1), mogwai_hash_2, 1_000));
+		assert_ok!(DotMogModule::buy_mogwai(Origin::signed(2), mogwai_hash_2, 1_000));
+		assert_eq!(DotMogModule::owner_of(mogwai_hash_2), Some(2));
+		assert!(DotMogModule::lock_of(mogwai_hash_2).is_none());
+
+		// an egg is locked until it hatches
+		assert_ok!(DotMogModule::create_mogwai(Origin::signed(2)));
+		let mogwai_hash_3 = DotMogModule::mogwai_by_index(2);
+		assert_ok!(DotMogModule::breed_mogwai(Origin::signed(2), mogwai_hash_2, mogwai_hash_3));
+		let egg_hash = DotMogModule::mogwai_by_index(3);
+		assert_eq!(DotMogModule::lock_of(egg_hash), Some(MogwaiLock::Incubating));
+		assert_noop!(DotMogModule::set_price(Origin::signed(2), egg_hash, 1_000), Error::<Test>::MogwaiLocked);
+		assert_noop!(DotMogModule::create_auction(Origin::signed(2), egg_hash, 100, 10), Error::<Test>::MogwaiLocked);
+
+		run_to_block(101);
+		assert!(DotMogModule::lock_of(egg_hash).is_none());
+		assert_ok!(DotMogModule::set_price(Origin::signed(2), egg_hash, 1_000));
+	});
+}
+
+#[test]
+fn test_dotmog_remove_auctioned_mogwai() {
+	new_test_ext().execute_with_checks(|| {
+		run_to_block(1);
+		assert_ok!(DotMogModule::mint_to(Origin::signed(FOUNDER), FOUNDER, H256::repeat_byte(1), 1, RarityType::Rare, true));
+		let mogwai_hash = DotMogModule::mogwai_of_owner_by_index((FOUNDER, 0u64));
+
+		assert_ok!(DotMogModule::create_auction(Origin::signed(FOUNDER), mogwai_hash, 100, 10));
+		assert_ok!(DotMogModule::bid_auction(Origin::signed(2), mogwai_hash, 200));
+		assert_ok!(DotMogModule::bid_auction(Origin::signed(3), mogwai_hash, 300));
+		assert_eq!(Balances::reserved_balance(2), 200);
+
+		// the forced removal cancels the auction and returns the bids
+		assert_ok!(DotMogModule::remove_mogwai(Origin::signed(FOUNDER), mogwai_hash));
+		assert!(DotMogModule::auction_of(mogwai_hash).is_none());
+		assert!(DotMogModule::auctions_expire_at(10).is_empty());
+		assert!(DotMogModule::bid_accounts(mogwai_hash).is_empty());
+		assert_eq!(DotMogModule::bid_of((mogwai_hash, 2u128)), 0);
+		assert!(DotMogModule::lock_of(mogwai_hash).is_none());
+		assert!(DotMogModule::game_events_of_mogwai(mogwai_hash).is_empty());
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(Balances::reserved_balance(3), 0);
+
+		// nothing is left to finalize
+		run_to_block(11);
+		assert_eq!(Balances::free_balance(2), INITIAL_BALANCE);
+		assert_eq!(Balances::free_balance(3), INITIAL_BALANCE);
+	});
+}
+
+#[test]
+fn test_dotmog_remove_incubating_mogwai() {
+	new_test_ext().execute_with_checks(|| {
+		assert_ok!(DotMogModule::create_mogwai(Origin::signed(1)));
+		assert_ok!(DotMogModule::create_mogwai(Origin::signed(1)));
+		let mogwai_hash_1 = DotMogModule::mogwai_by_index(0);
+		let mogwai_hash_2 = DotMogModule::mogwai_by_index(1);
+		assert_ok!(DotMogModule::breed_mogwai(Origin::signed(1), mogwai_hash_1, mogwai_hash_2));
+		assert_ok!(DotMogModule::breed_mogwai(Origin::signed(1), mogwai_hash_1, mogwai_hash_2));
+		let egg_hash_1 = DotMogModule::mogwai_by_index(2);
+		let egg_hash_2 = DotMogModule::mogwai_by_index(3);
+		assert_eq!(DotMogModule::all_game_events_count(), 2);
+
+		assert_ok!(DotMogModule::remove(1, egg_hash_1));
+		assert!(DotMogModule::game_events_of_mogwai(egg_hash_1).is_empty());
+		assert!(DotMogModule::lock_of(egg_hash_1).is_none());
+		assert_eq!(DotMogModule::all_game_events_count(), 1);
+		assert_eq!(DotMogModule::game_events_at_block(100).len(), 1);
+
+		// the other egg still hatches
+		run_to_block(101);
+		assert_eq!(DotMogModule::mogwai_bios(egg_hash_2).level, 1);
+		assert!(!MogwaisBios::<Test>::contains_key(egg_hash_1));
+	});
+}
+
+#[test]
+fn test_dotmog_migrate_lifecycle_locks() {
+	new_test_ext().execute_with_checks(|| {
+		run_to_block(5);
+		assert_ok!(DotMogModule::create_mogwai(Origin::signed(1)));
+		assert_ok!(DotMogModule::create_mogwai(Origin::signed(1)));
+		assert_ok!(DotMogModule::create_mogwai(Origin::signed(1)));
+		let mogwai_hash_1 = DotMogModule::mogwai_by_index(0);
+		let mogwai_hash_2 = DotMogModule::mogwai_by_index(1);
+		let mogwai_hash_3 = DotMogModule::mogwai_by_index(2);
+		assert_ok!(DotMogModule::create_auction(Origin::signed(1), mogwai_hash_1, 100, 10));
+		assert_ok!(DotMogModule::breed_mogwai(Origin::signed(1), mogwai_hash_2, mogwai_hash_3));
+		let egg_hash = DotMogModule::mogwai_by_index(3);
+		assert_ok!(DotMogModule::set_price(Origin::signed(1), mogwai_hash_2, 100));
+
+		// the state before the locks, with a stale auction expired without a sale, its bids still reserved
+		let stale_auction = Auction { expiry: 3, ..DotMogModule::auction_of(mogwai_hash_1).unwrap() };
+		<MogwaiAuction<Test>>::insert(mogwai_hash_3, stale_auction.clone());
+		<Auctions<Test>>::insert(3, vec![stale_auction]);
+		assert_ok!(Balances::reserve(&2, 200));
+		<Bids<Test>>::insert((mogwai_hash_3, 2u128), 200);
+		<BidAccounts<Test>>::insert(mogwai_hash_3, vec![2u128]);
+		let mut egg = DotMogModule::mogwai(egg_hash);
+		egg.price = 100;
+		<Mogwais<Test>>::insert(egg_hash, egg);
+		let _ = <MogwaiLocks<Test>>::drain();
+		StorageVersion::<Test>::put(Releases::V5_0_0);
+
+		DotMogModule::on_runtime_upgrade();
+
+		assert_eq!(DotMogModule::storage_version(), Releases::V6_0_0);
+		assert_eq!(DotMogModule::lock_of(mogwai_hash_1), Some(MogwaiLock::Auctioned));
+		assert_eq!(DotMogModule::lock_of(mogwai_hash_2), Some(MogwaiLock::Listed));
+		assert!(DotMogModule::lock_of(mogwai_hash_3).is_none());
+		assert!(DotMogModule::auction_of(mogwai_hash_3).is_none());
+		assert!(DotMogModule::auctions_expire_at(3).is_empty());
+		assert!(DotMogModule::bid_accounts(mogwai_hash_3).is_empty());
+		assert_eq!(DotMogModule::bid_of((mogwai_hash_3, 2u128)), 0);
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(DotMogModule::lock_of(egg_hash), Some(MogwaiLock::Incubating));
+		assert_eq!(DotMogModule::mogwai(egg_hash).price, 0);
+	});
+}
+
+#[test]
+fn test_dotmog_try_state() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(DotMogModule::create_mogwai(Origin::signed(1)));
+		assert_ok!(DotMogModule::create_mogwai(Origin::signed(1)));
+		let mogwai_hash_1 = DotMogModule::mogwai_by_index(0);
+		let mogwai_hash_2 = DotMogModule::mogwai_by_index(1);
+		assert_ok!(DotMogModule::breed_mogwai(Origin::signed(1), mogwai_hash_1, mogwai_hash_2));
+		assert_ok!(DotMogModule::do_try_state());
+
+		// a broken swap and pop leaves the owned index pointing at the wrong slot
+		<OwnedMogwaisIndex<Test>>::insert(mogwai_hash_1, 1);
+		assert_eq!(DotMogModule::do_try_state(), Err("OwnedMogwaisArray differs from OwnedMogwaisIndex"));
+		<OwnedMogwaisIndex<Test>>::insert(mogwai_hash_1, 0);
+
+		// a game event missing from the index of its type
+		let game_event_id = DotMogModule::game_event_by_index(0);
+		<GameEventsIndex<Test>>::remove(game_event_id);
+		assert!(DotMogModule::do_try_state().is_err());
+		<GameEventsIndex<Test>>::insert(game_event_id, 0);
+
+		// a game event that lost its mogwai
+		<GameEventsOfMogwai<Test>>::remove(DotMogModule::mogwai_by_index(2));
+		assert_eq!(DotMogModule::do_try_state(), Err("GameEventsOfMogwai differs from the mogwais of the game events"));
+	});
+}
+
+/// leave an auction with a bid of account 2 and a game event behind, like removing a mogwai used to.
+fn leave_stale_entries(mogwai_hash: H256, expiry: u64) {
+	assert_ok!(Balances::reserve(&2, 200));
+	<MogwaiAuction<Test>>::insert(mogwai_hash, Auction {
+		mogwai_id: mogwai_hash, mogwai_owner: 1, expiry, min_bid: 100, high_bid: 200, high_bidder: 2,
+	});
+	<Bids<Test>>::insert((mogwai_hash, 2u128), 200);
+	<BidAccounts<Test>>::insert(mogwai_hash, vec![2u128]);
+	<GameEventsOfMogwai<Test>>::insert(mogwai_hash, vec![H256::repeat_byte(9)]);
+}
+
+#[test]
+fn test_dotmog_reap_stale() {
+	new_test_ext().execute_with_checks(|| {
+		run_to_block(5);
+		assert_ok!(DotMogModule::create_mogwai(Origin::signed(1)));
+		let mogwai_hash = DotMogModule::mogwai_by_index(0);
+		let removed_hash = H256::repeat_byte(1);
+		leave_stale_entries(removed_hash, 10);
+
+		// the pallet account covers three rewards and stays alive
+		let _ = Balances::deposit_creating(&DotMogModule::reward_account(), 1 + 3 * ReapReward::get());
+
+		// live mogwais and too many keys can't be reaped
+		assert_noop!(DotMogModule::reap_stale(Origin::signed(3), vec![mogwai_hash]), Error::<Test>::NothingStale);
+		assert_noop!(DotMogModule::reap_stale(Origin::signed(3), vec![mogwai_hash; 17]), Error::<Test>::MaxReapKeys);
+
+		// the auction and the game events of the removed mogwai are stale, the bid is returned
+		assert_ok!(DotMogModule::reap_stale(Origin::signed(3), vec![removed_hash, mogwai_hash, removed_hash]));
+		assert!(DotMogModule::auction_of(removed_hash).is_none());
+		assert!(DotMogModule::bid_accounts(removed_hash).is_empty());
+		assert_eq!(DotMogModule::bid_of((removed_hash, 2u128)), 0);
+		assert!(!<GameEventsOfMogwai<Test>>::contains_key(removed_hash));
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(Balances::free_balance(3), INITIAL_BALANCE + 2 * ReapReward::get());
+
+		// an auction past its expiry is stale as well, releasing the mogwai
+		leave_stale_entries(mogwai_hash, 4);
+		<GameEventsOfMogwai<Test>>::remove(mogwai_hash);
+		<MogwaiLocks<Test>>::insert(mogwai_hash, MogwaiLock::Auctioned);
+		assert_ok!(DotMogModule::reap_stale(Origin::signed(3), vec![mogwai_hash]));
+		assert!(DotMogModule::auction_of(mogwai_hash).is_none());
+		assert!(DotMogModule::lock_of(mogwai_hash).is_none());
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(Balances::free_balance(3), INITIAL_BALANCE + 3 * ReapReward::get());
+
+		// bids without an auction are stale
+		assert_ok!(Balances::reserve(&2, 200));
+		<Bids<Test>>::insert((removed_hash, 2u128), 200);
+		<BidAccounts<Test>>::insert(removed_hash, vec![2u128]);
+		assert_ok!(DotMogModule::reap_stale(Origin::signed(3), vec![removed_hash]));
+		assert!(DotMogModule::bid_accounts(removed_hash).is_empty());
+		assert_eq!(Balances::reserved_balance(2), 0);
+
+		// the pallet account can't cover any more rewards, so nothing is paid
+		assert_eq!(Balances::free_balance(3), INITIAL_BALANCE + 3 * ReapReward::get());
+		assert_eq!(Balances::free_balance(DotMogModule::reward_account()), 1);
+	});
+}
+
+#[test]
+fn test_dotmog_sweep_stale() {
+	new_test_ext().execute_with_checks(|| {
+		run_to_block(5);
+		assert_ok!(DotMogModule::create_mogwai(Origin::signed(1)));
+		assert_ok!(DotMogModule::create_mogwai(Origin::signed(1)));
+		let mogwai_hash_1 = DotMogModule::mogwai_by_index(0);
+		let mogwai_hash_2 = DotMogModule::mogwai_by_index(1);
+		assert_ok!(DotMogModule::breed_mogwai(Origin::signed(1), mogwai_hash_1, mogwai_hash_2));
+		assert_ok!(DotMogModule::create_auction(Origin::signed(1), mogwai_hash_1, 100, 50));
+
+		// entries of removed mogwais, and an auction bucket of a past block
+		for index in 1..4 {
+			leave_stale_entries(H256::repeat_byte(index), 3);
+		}
+		<Auctions<Test>>::insert(4, vec![DotMogModule::auction_of(H256::repeat_byte(1)).unwrap()]);
+		assert_eq!(Balances::reserved_balance(2), 600);
+
+		// the idle blocks sweep them away
+		run_to_block(20);
+		for index in 1..4 {
+			let removed_hash = H256::repeat_byte(index);
+			assert!(DotMogModule::auction_of(removed_hash).is_none());
+			assert!(DotMogModule::bid_accounts(removed_hash).is_empty());
+			assert!(!<GameEventsOfMogwai<Test>>::contains_key(removed_hash));
+		}
+		assert!(DotMogModule::auctions_expire_at(4).is_empty());
+		assert_eq!(Balances::reserved_balance(2), 0);
+
+		// entries in use are kept
+		assert!(DotMogModule::auction_of(mogwai_hash_1).is_some());
+		assert_eq!(DotMogModule::auctions_expire_at(50).len(), 1);
+		assert_eq!(DotMogModule::game_events_of_mogwai(DotMogModule::mogwai_by_index(2)).len(), 1);
+
+		// a full pass without anything stale pauses the sweep
+		run_to_block(40);
+		let paused_until = DotMogModule::sweep_paused_until();
+		assert!(paused_until > 40 && paused_until <= 40 + SWEEP_PAUSE_BLOCKS as u64);
+		assert_eq!(DotMogModule::sweep_cursor(), (0, Vec::new(), 0));
+
+		// entries going stale later are swept once the pause is over, in blocks with space left
+		leave_stale_entries(H256::repeat_byte(4), 3);
+		run_to_block(paused_until);
+		System::register_extra_weight_unchecked(u64::MAX, DispatchClass::Mandatory);
+		run_to_block(paused_until + 1);
+		assert_eq!(DotMogModule::sweep_cursor(), (0, Vec::new(), 0));
+		assert!(DotMogModule::auction_of(H256::repeat_byte(4)).is_some());
+
+		run_to_block(paused_until + 20);
+		assert!(DotMogModule::auction_of(H256::repeat_byte(4)).is_none());
+		assert!(DotMogModule::bid_accounts(H256::repeat_byte(4)).is_empty());
+		assert_eq!(Balances::reserved_balance(2), 0);
+	});
+}
+
+#[test]
+fn test_dotmog_nonfungibles() {
+	use frame_support::traits::tokens::nonfungibles::{Inspect, Transfer, Mutate};
+
+	new_test_ext().execute_with_checks(|| {
+		run_to_block(1);
+		assert_ok!(DotMogModule::mint_to(Origin::signed(FOUNDER), 1, H256::repeat_byte(1), 2, RarityType::Rare, true));
+		let mogwai_hash = DotMogModule::mogwai_of_owner_by_index((1u128, 0u64));
+		let mogwai = DotMogModule::mogwai(mogwai_hash);
+
+		// inspect
+		assert_eq!(<DotMogModule as Inspect<u128>>::owner(&(), &mogwai_hash), Some(1));
+		assert_eq!(<DotMogModule as Inspect<u128>>::class_owner(&()), Some(FOUNDER));
+		assert_eq!(<DotMogModule as Inspect<u128>>::attribute(&(), &mogwai_hash, b"dna"), Some(mogwai.dna.encode()));
+		assert_eq!(<DotMogModule as Inspect<u128>>::attribute(&(), &mogwai_hash, b"gen"), Some(mogwai.gen.encode()));
+		assert_eq!(<DotMogModule as Inspect<u128>>::attribute(&(), &mogwai_hash, b"rarity"), Some(RarityType::Rare.encode()));
+		assert_eq!(<DotMogModule as Inspect<u128>>::attribute(&(), &mogwai_hash, b"level"), Some(DotMogModule::mogwai_bios(mogwai_hash).level.encode()));
+		assert_eq!(<DotMogModule as Inspect<u128>>::attribute(&(), &mogwai_hash, b"name"), None);
+		assert_eq!(<DotMogModule as Inspect<u128>>::attribute(&(), &H256::repeat_byte(9), b"dna"), None);
+		assert!(<DotMogModule as Inspect<u128>>::can_transfer(&(), &mogwai_hash));
+
+		// transfer
+		assert_ok!(<DotMogModule as Transfer<u128>>::transfer(&(), &mogwai_hash, &2));
+		assert_eq!(DotMogModule::owner_of(mogwai_hash), Some(2));
+		assert_eq!(DotMogModule::owned_mogwais_count(&1), 0);
+		assert_eq!(DotMogModule::owned_mogwais_count(&2), 1);
+		assert_noop!(<DotMogModule as Transfer<u128>>::transfer(&(), &H256::repeat_byte(9), &2), Error::<Test>::MogwaiDoesntExists);
+
+		// locked mogwais can't be transferred or burned
+		assert_ok!(DotMogModule::create_auction(Origin::signed(2), mogwai_hash, 100, 10));
+		assert!(!<DotMogModule as Inspect<u128>>::can_transfer(&(), &mogwai_hash));
+		assert_noop!(<DotMogModule as Transfer<u128>>::transfer(&(), &mogwai_hash, &1), Error::<Test>::MogwaiLocked);
+		assert_noop!(<DotMogModule as Mutate<u128>>::burn_from(&(), &mogwai_hash), Error::<Test>::MogwaiLocked);
+
+		// mint and burn
+		let minted_hash = H256::repeat_byte(7);
+		assert_ok!(<DotMogModule as Mutate<u128>>::mint_into(&(), &minted_hash, &3));
+		assert_eq!(DotMogModule::owner_of(minted_hash), Some(3));
+		assert_eq!(DotMogModule::mogwai(minted_hash).dna, minted_hash);
+		assert_eq!(DotMogModule::all_mogwais_count(), 2);
+		assert_noop!(<DotMogModule as Mutate<u128>>::mint_into(&(), &minted_hash, &1), Error::<Test>::MogwaiAlreadyExists);
+
+		assert_ok!(<DotMogModule as Mutate<u128>>::burn_from(&(), &minted_hash));
+		assert!(DotMogModule::owner_of(minted_hash).is_none());
+		assert_eq!(DotMogModule::owned_mogwais_count(&3), 0);
+		assert_eq!(DotMogModule::all_mogwais_count(), 1);
+		assert_noop!(<DotMogModule as Mutate<u128>>::burn_from(&(), &minted_hash), Error::<Test>::MogwaiDoesntExists);
+	});
+}